@@ -1,12 +1,31 @@
 use crate::{check_korean, first_letter_check, last_letter_check, middle_letter_check};
 
+/// Initial consonants (choseong) in Unicode composition order.
+pub const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Medial vowels (jungseong) in Unicode composition order.
+pub const JUNGSEONG: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+
+/// Final consonants (jongseong) in Unicode composition order.
+/// Index 0 (`' '`) stands for a syllable without a final consonant.
+pub const JONGSEONG: [char; 28] = [
+    ' ', 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ',
+    'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
 /// Composes a Korean string from a vector of individual Hangul characters (jamo),
 /// combining them into complete syllables where possible.
 ///
 /// This function processes the given Hangul jamo characters (`chars_vec`) sequentially,
-/// combining them into complete Hangul syllables according to the rules for forming
-/// Korean syllables. The jamo are combined in the order of initial consonant (choseong),
-/// medial vowel (jungseong), and final consonant (jongseong).
+/// combining them into complete Hangul syllables the way a Dubeolsik input method does. The
+/// jamo are combined in the order of initial consonant (choseong), medial vowel (jungseong),
+/// and final consonant (jongseong).
 ///
 /// # Arguments
 ///
@@ -25,56 +44,19 @@ use crate::{check_korean, first_letter_check, last_letter_check, middle_letter_c
 /// let chars_vec = vec!['ㅎ', 'ㅏ', 'ㄴ', 'ㄱ', 'ㅡ', 'ㄹ'];
 /// let result = compose_korean(chars_vec);
 /// assert_eq!(result, "한글");
+/// assert_eq!(compose_korean(vec!['ㄷ', 'ㅏ', 'ㄹ', 'ㄱ', 'ㄱ', 'ㅗ', 'ㅏ']), "닭과");
 /// ```
 ///
-/// This function uses the `combine_status_check` function to determine whether each jamo
-/// can be added to the currently forming syllable string, and the `make_one_letter` function
-/// to actually combine the jamo into complete Hangul syllables. If the jamo characters cannot
-/// be combined according to the rules, each jamo is added as an individual character to the
-/// resulting string.
+/// Two vowels or two consonants that make a compound jamo (ㅗ + ㅏ = ㅘ, ㄹ + ㄱ = ㄺ) are
+/// combined, and a final consonant moves to the next syllable when a vowel follows it. A jamo
+/// that cannot join the current syllable starts the next one (ㄱㅏㄸㅏ gives "가따"), and
+/// characters that are not jamo are added to the resulting string as they are.
 pub fn compose_korean(chars_vec: Vec<char>) -> String {
-    // where to save the final result
-    let mut result = String::new();
-    // making the temp one korean char
-    let mut combined_one_char = String::new();
-
+    let mut composer = JamoComposer::default();
     for one_char in chars_vec {
-        // maximum length check
-        if combined_one_char.chars().count() == 3 {
-            // last_letter could combine with next middle_letter
-            if middle_letter_check(one_char) {
-                let split_last_letter = combined_one_char.pop().unwrap();
-                result.push(make_one_letter(combined_one_char.clone()));
-                combined_one_char.clear();
-                combined_one_char.push(split_last_letter);
-
-            // else just push and combine as a result
-            } else {
-                result.push(make_one_letter(combined_one_char.clone()));
-                combined_one_char.clear();
-            }
-        }
-
-        // combined_logic started
-        if combine_status_check(&combined_one_char, &one_char) {
-            combined_one_char.push(one_char);
-        } else {
-            // if exist previous saving temp
-            if !combined_one_char.is_empty() {
-                result.push(make_one_letter(combined_one_char.clone()));
-                combined_one_char.clear();
-                result.push(one_char);
-            } else {
-                result.push(one_char);
-            }
-        }
-    }
-
-    if !combined_one_char.is_empty() {
-        result.push(make_one_letter(combined_one_char.clone()));
+        composer.push(one_char);
     }
-
-    result
+    composer.finish()
 }
 
 /// Checks if a character can be combined with a given string to form a valid Korean syllable.
@@ -135,26 +117,13 @@ pub fn make_one_letter(combine_string: String) -> char {
         return combine_string.clone().pop().unwrap();
     }
 
-    let cho = [
-        'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ',
-        'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
-    ];
-    let jung = [
-        'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ',
-        'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
-    ];
-    let jong = [
-        ' ', 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ',
-        'ㅀ', 'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
-    ];
-
     let (mut cho_index, mut jung_index, mut jong_index) = (0, 0, 0);
 
     for (index, ch) in combine_string.chars().enumerate() {
         match index {
-            0 => cho_index = cho.iter().position(|&c| c == ch).unwrap_or_default(),
-            1 => jung_index = jung.iter().position(|&c| c == ch).unwrap_or_default(),
-            2 => jong_index = jong.iter().position(|&c| c == ch).unwrap_or_default(),
+            0 => cho_index = CHOSEONG.iter().position(|&c| c == ch).unwrap_or_default(),
+            1 => jung_index = JUNGSEONG.iter().position(|&c| c == ch).unwrap_or_default(),
+            2 => jong_index = JONGSEONG.iter().position(|&c| c == ch).unwrap_or_default(),
             _ => panic!("Invalid input"),
         }
     }
@@ -163,6 +132,74 @@ pub fn make_one_letter(combine_string: String) -> char {
     std::char::from_u32(result_code as u32).unwrap_or_else(|| panic!("Invalid Hangul character"))
 }
 
+/// Splits a complete Hangul syllable into its initial consonant (choseong), medial vowel
+/// (jungseong) and optional final consonant (jongseong). This is the inverse of `make_one_letter`.
+///
+/// # Arguments
+/// * `character` - A `char` to split.
+///
+/// # Returns
+/// * `Option<(char, char, Option<char>)>` - The jamo of the syllable, or `None` if the character
+///   is not a complete Hangul syllable.
+///
+/// # Examples
+/// ```
+/// use rustkorean::compose_korean::split_one_letter;
+///
+/// assert_eq!(split_one_letter('한'), Some(('ㅎ', 'ㅏ', Some('ㄴ'))));
+/// assert_eq!(split_one_letter('가'), Some(('ㄱ', 'ㅏ', None)));
+/// assert_eq!(split_one_letter('ㄱ'), None);
+/// ```
+pub fn split_one_letter(character: char) -> Option<(char, char, Option<char>)> {
+    let unicode = character as u32;
+    if !(44032..=55203).contains(&unicode) {
+        return None;
+    }
+
+    let offset = (unicode - 44032) as usize;
+    let jong = match offset % 28 {
+        0 => None,
+        jong_index => Some(JONGSEONG[jong_index]),
+    };
+    Some((CHOSEONG[offset / 588], JUNGSEONG[offset % 588 / 28], jong))
+}
+
+/// Decomposes a string into individual Hangul jamo. This is the inverse of `compose_korean`.
+///
+/// Complete Hangul syllables are split into choseong, jungseong and jongseong;
+/// every other character is kept as it is.
+///
+/// # Arguments
+/// * `text` - A `&str` to decompose.
+///
+/// # Returns
+/// * `Vec<char>` - The jamo and untouched characters, in order.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{compose_korean, decompose_korean};
+///
+/// let jamo = decompose_korean("한글 A");
+/// assert_eq!(jamo, vec!['ㅎ', 'ㅏ', 'ㄴ', 'ㄱ', 'ㅡ', 'ㄹ', ' ', 'A']);
+/// assert_eq!(compose_korean(jamo), "한글 A");
+/// ```
+pub fn decompose_korean(text: &str) -> Vec<char> {
+    let mut result = Vec::new();
+
+    for character in text.chars() {
+        match split_one_letter(character) {
+            Some((cho, jung, jong)) => {
+                result.push(cho);
+                result.push(jung);
+                result.extend(jong);
+            }
+            None => result.push(character),
+        }
+    }
+
+    result
+}
+
 /// Converts a vector of individual Korean consonants into a vector with combined double consonants.
 ///
 /// This function examines a sequence of Korean consonants and combines them into double consonants
@@ -198,57 +235,260 @@ pub fn create_double_consonant(chars_vec: Vec<char>) -> Vec<char> {
             continue;
         }
 
-        if let Some(&&next_ch) = iter.peek() {
-            match (ch, next_ch) {
-                ('ㄱ', 'ㅅ') => {
-                    result.push('ㄳ');
-                    skip_next = true;
-                }
-                ('ㄴ', 'ㅈ') => {
-                    result.push('ㄵ');
-                    skip_next = true;
-                }
-                ('ㄴ', 'ㅎ') => {
-                    result.push('ㄶ');
-                    skip_next = true;
-                }
-                ('ㄹ', 'ㄱ') => {
-                    result.push('ㄺ');
-                    skip_next = true;
-                }
-                ('ㄹ', 'ㅁ') => {
-                    result.push('ㄻ');
-                    skip_next = true;
-                }
-                ('ㄹ', 'ㅂ') => {
-                    result.push('ㄼ');
-                    skip_next = true;
-                }
-                ('ㄹ', 'ㅅ') => {
-                    result.push('ㄽ');
-                    skip_next = true;
-                }
-                ('ㄹ', 'ㅌ') => {
-                    result.push('ㄾ');
-                    skip_next = true;
-                }
-                ('ㄹ', 'ㅍ') => {
-                    result.push('ㄿ');
-                    skip_next = true;
-                }
-                ('ㄹ', 'ㅎ') => {
-                    result.push('ㅀ');
-                    skip_next = true;
-                }
-                ('ㅂ', 'ㅅ') => {
-                    result.push('ㅄ');
-                    skip_next = true;
-                }
-                _ => result.push(ch),
+        match iter
+            .peek()
+            .and_then(|&&next_ch| double_consonant(ch, next_ch))
+        {
+            Some(double) => {
+                result.push(double);
+                skip_next = true;
             }
-        } else {
-            result.push(ch);
+            None => result.push(ch),
         }
     }
     result
 }
+
+/// Compound final consonants (겹받침) and the two consonants each one is made of.
+const DOUBLE_CONSONANTS: [(char, char, char); 11] = [
+    ('ㄱ', 'ㅅ', 'ㄳ'),
+    ('ㄴ', 'ㅈ', 'ㄵ'),
+    ('ㄴ', 'ㅎ', 'ㄶ'),
+    ('ㄹ', 'ㄱ', 'ㄺ'),
+    ('ㄹ', 'ㅁ', 'ㄻ'),
+    ('ㄹ', 'ㅂ', 'ㄼ'),
+    ('ㄹ', 'ㅅ', 'ㄽ'),
+    ('ㄹ', 'ㅌ', 'ㄾ'),
+    ('ㄹ', 'ㅍ', 'ㄿ'),
+    ('ㄹ', 'ㅎ', 'ㅀ'),
+    ('ㅂ', 'ㅅ', 'ㅄ'),
+];
+
+/// Compound vowels and the two vowels each one is made of.
+const DOUBLE_VOWELS: [(char, char, char); 7] = [
+    ('ㅗ', 'ㅏ', 'ㅘ'),
+    ('ㅗ', 'ㅐ', 'ㅙ'),
+    ('ㅗ', 'ㅣ', 'ㅚ'),
+    ('ㅜ', 'ㅓ', 'ㅝ'),
+    ('ㅜ', 'ㅔ', 'ㅞ'),
+    ('ㅜ', 'ㅣ', 'ㅟ'),
+    ('ㅡ', 'ㅣ', 'ㅢ'),
+];
+
+/// Combines two consonants into a compound final consonant (e.g. ㄹ + ㄱ → ㄺ).
+///
+/// # Examples
+/// ```
+/// use rustkorean::compose_korean::double_consonant;
+///
+/// assert_eq!(double_consonant('ㄹ', 'ㄱ'), Some('ㄺ'));
+/// assert_eq!(double_consonant('ㄱ', 'ㄹ'), None);
+/// ```
+pub fn double_consonant(first: char, second: char) -> Option<char> {
+    DOUBLE_CONSONANTS
+        .iter()
+        .find(|&&(a, b, _)| a == first && b == second)
+        .map(|&(_, _, double)| double)
+}
+
+/// Splits a compound final consonant into its two consonants (e.g. ㄺ → ㄹ, ㄱ).
+/// This is the inverse of `double_consonant`.
+///
+/// # Examples
+/// ```
+/// use rustkorean::compose_korean::split_double_consonant;
+///
+/// assert_eq!(split_double_consonant('ㄺ'), Some(('ㄹ', 'ㄱ')));
+/// assert_eq!(split_double_consonant('ㄲ'), None);
+/// ```
+pub fn split_double_consonant(character: char) -> Option<(char, char)> {
+    DOUBLE_CONSONANTS
+        .iter()
+        .find(|&&(_, _, double)| double == character)
+        .map(|&(a, b, _)| (a, b))
+}
+
+/// Combines two vowels into a compound vowel (e.g. ㅗ + ㅏ → ㅘ).
+///
+/// # Examples
+/// ```
+/// use rustkorean::compose_korean::double_vowel;
+///
+/// assert_eq!(double_vowel('ㅗ', 'ㅏ'), Some('ㅘ'));
+/// assert_eq!(double_vowel('ㅏ', 'ㅗ'), None);
+/// ```
+pub fn double_vowel(first: char, second: char) -> Option<char> {
+    DOUBLE_VOWELS
+        .iter()
+        .find(|&&(a, b, _)| a == first && b == second)
+        .map(|&(_, _, double)| double)
+}
+
+/// Splits a compound vowel into its two vowels (e.g. ㅘ → ㅗ, ㅏ).
+/// This is the inverse of `double_vowel`.
+///
+/// # Examples
+/// ```
+/// use rustkorean::compose_korean::split_double_vowel;
+///
+/// assert_eq!(split_double_vowel('ㅘ'), Some(('ㅗ', 'ㅏ')));
+/// assert_eq!(split_double_vowel('ㅐ'), None);
+/// ```
+pub fn split_double_vowel(character: char) -> Option<(char, char)> {
+    DOUBLE_VOWELS
+        .iter()
+        .find(|&&(_, _, double)| double == character)
+        .map(|&(a, b, _)| (a, b))
+}
+
+/// Returns the tense (doubled) form of an initial consonant (e.g. ㄱ → ㄲ).
+///
+/// # Examples
+/// ```
+/// use rustkorean::compose_korean::tense_consonant;
+///
+/// assert_eq!(tense_consonant('ㄱ'), Some('ㄲ'));
+/// assert_eq!(tense_consonant('ㄴ'), None);
+/// ```
+pub fn tense_consonant(character: char) -> Option<char> {
+    match character {
+        'ㄱ' => Some('ㄲ'),
+        'ㄷ' => Some('ㄸ'),
+        'ㅂ' => Some('ㅃ'),
+        'ㅅ' => Some('ㅆ'),
+        'ㅈ' => Some('ㅉ'),
+        _ => None,
+    }
+}
+
+/// Builds Hangul syllables out of a stream of jamo the way a Hangul input method does.
+///
+/// `push` follows Dubeolsik rules, where a consonant becomes an initial or a final depending
+/// on what comes next; `compose_korean` and the phone keypads compose with it. `push_initial`,
+/// `push_medial` and `push_final` are for input methods whose keys already say where the jamo
+/// goes, such as Sebeolsik.
+#[derive(Default)]
+pub(crate) struct JamoComposer {
+    result: String,
+    cho: Option<char>,
+    jung: Option<char>,
+    jong: Option<char>,
+}
+
+impl JamoComposer {
+    pub(crate) fn push(&mut self, character: char) {
+        if middle_letter_check(character) {
+            self.push_vowel(character);
+        } else if first_letter_check(character) || last_letter_check(character) {
+            self.push_consonant(character);
+        } else {
+            self.push_other(character);
+        }
+    }
+
+    pub(crate) fn push_initial(&mut self, character: char) {
+        match (self.cho, self.jung) {
+            (Some(cho), None) if cho == character && tense_consonant(cho).is_some() => {
+                self.cho = tense_consonant(cho);
+            }
+            _ => {
+                self.flush();
+                self.cho = Some(character);
+            }
+        }
+    }
+
+    pub(crate) fn push_medial(&mut self, character: char) {
+        match (self.jung, self.jong) {
+            (Some(jung), None) if double_vowel(jung, character).is_some() => {
+                self.jung = double_vowel(jung, character);
+            }
+            (None, None) => self.jung = Some(character),
+            _ => {
+                self.flush();
+                self.jung = Some(character);
+            }
+        }
+    }
+
+    pub(crate) fn push_final(&mut self, character: char) {
+        match (self.cho, self.jung, self.jong) {
+            (Some(_), Some(_), None) => self.jong = Some(character),
+            (Some(_), Some(_), Some(jong)) if double_consonant(jong, character).is_some() => {
+                self.jong = double_consonant(jong, character);
+            }
+            _ => {
+                self.flush();
+                self.result.push(character);
+            }
+        }
+    }
+
+    pub(crate) fn push_other(&mut self, character: char) {
+        self.flush();
+        self.result.push(character);
+    }
+
+    pub(crate) fn finish(mut self) -> String {
+        self.flush();
+        self.result
+    }
+
+    fn push_consonant(&mut self, character: char) {
+        match (self.cho, self.jung, self.jong) {
+            (Some(_), Some(_), None) if last_letter_check(character) => {
+                self.jong = Some(character);
+            }
+            (Some(_), Some(_), Some(jong)) if double_consonant(jong, character).is_some() => {
+                self.jong = double_consonant(jong, character);
+            }
+            _ if first_letter_check(character) => {
+                self.flush();
+                self.cho = Some(character);
+            }
+            _ => self.push_other(character),
+        }
+    }
+
+    fn push_vowel(&mut self, character: char) {
+        match (self.cho, self.jung, self.jong) {
+            (_, Some(jung), None) if double_vowel(jung, character).is_some() => {
+                self.jung = double_vowel(jung, character);
+            }
+            (Some(_), None, _) => self.jung = Some(character),
+            (Some(_), Some(_), Some(jong)) => {
+                // the final consonant moves over to start the next syllable
+                let (kept, moved) = match split_double_consonant(jong) {
+                    Some((kept, moved)) => (Some(kept), moved),
+                    None => (None, jong),
+                };
+                self.jong = kept;
+                self.flush();
+                self.cho = Some(moved);
+                self.jung = Some(character);
+            }
+            _ => {
+                self.flush();
+                self.jung = Some(character);
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        match (self.cho, self.jung) {
+            (Some(cho), Some(jung)) => {
+                let letter: String = [Some(cho), Some(jung), self.jong]
+                    .iter()
+                    .flatten()
+                    .collect();
+                self.result.push(make_one_letter(letter));
+            }
+            _ => self
+                .result
+                .extend([self.cho, self.jung, self.jong].iter().flatten()),
+        }
+        self.cho = None;
+        self.jung = None;
+        self.jong = None;
+    }
+}
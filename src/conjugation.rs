@@ -0,0 +1,380 @@
+use crate::compose_korean::{make_one_letter, split_one_letter};
+use crate::enums::ConjugationType;
+
+/// Stems ending in ㄷ that are ㄷ irregular; other ㄷ stems (받다, 닫다) are regular.
+const DIGEUT_IRREGULAR: [&str; 10] = [
+    "듣", "걷", "묻", "싣", "깨닫", "붇", "일컫", "긷", "눋", "치닫",
+];
+
+/// Stems ending in ㅂ that are regular; other ㅂ stems (돕다, 춥다) are ㅂ irregular.
+const BIEUP_REGULAR: [&str; 10] = ["잡", "입", "씹", "업", "뽑", "좁", "접", "집", "꼽", "수줍"];
+
+/// Stems ending in ㅅ that are ㅅ irregular; other ㅅ stems (웃다, 씻다) are regular.
+const SIOT_IRREGULAR: [&str; 7] = ["낫", "짓", "긋", "붓", "잇", "젓", "잣"];
+
+/// Stems ending in ㅎ that are regular; other ㅎ stems (파랗다, 그렇다) are ㅎ irregular.
+const HIEUT_REGULAR: [&str; 8] = ["좋", "낳", "놓", "넣", "닿", "쌓", "땋", "찧"];
+
+/// Stems ending in 르 that only drop ㅡ (따라) instead of being 르 irregular.
+const REU_REGULAR: [&str; 6] = ["따르", "치르", "들르", "우러르", "다다르", "잇따르"];
+
+/// Stems ending in 르 that are 러 irregular.
+const REO_IRREGULAR: [&str; 2] = ["푸르", "노르"];
+
+/// How an ending attaches to a stem.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EndingKind {
+    /// -아/어 endings, chosen by vowel harmony.
+    Harmony,
+    /// -았/었 past endings.
+    Past,
+    /// -(으) endings, with 으 after a final consonant.
+    Eu,
+    /// Endings such as -ㅂ/습니다: the jamo becomes the final after a vowel,
+    /// the syllable follows a final consonant.
+    Batchim(char, char),
+    /// Endings attached as they are.
+    Plain,
+}
+
+/// Returns how a verb or adjective stem conjugates, judged from the common irregular stems.
+///
+/// A stem may be given with or without 다 (듣다, 듣). Stems not known to be irregular follow
+/// their final: most ㅂ and ㅎ stems are irregular, most ㄷ and ㅅ stems are regular and
+/// stems ending in 르 are 르 irregular.
+///
+/// # Arguments
+/// * `stem` - A verb or adjective stem.
+///
+/// # Returns
+/// * `ConjugationType` - How the stem conjugates.
+///
+/// # Examples
+/// ```
+/// use rustkorean::conjugation::conjugation_type;
+/// use rustkorean::ConjugationType;
+///
+/// assert_eq!(conjugation_type("듣다"), ConjugationType::Digeut);
+/// assert_eq!(conjugation_type("받다"), ConjugationType::Regular);
+/// assert_eq!(conjugation_type("춥다"), ConjugationType::Bieup);
+/// assert_eq!(conjugation_type("모르다"), ConjugationType::Reu);
+/// ```
+pub fn conjugation_type(stem: &str) -> ConjugationType {
+    let stem = strip_da(stem);
+    let ends_with = |stems: &[&str]| stems.iter().any(|known| stem.ends_with(known));
+    let Some((_, _, jong)) = stem.chars().last().and_then(split_one_letter) else {
+        return ConjugationType::Regular;
+    };
+
+    match jong {
+        Some('ㄷ') if ends_with(&DIGEUT_IRREGULAR) => ConjugationType::Digeut,
+        Some('ㅂ') if !ends_with(&BIEUP_REGULAR) => ConjugationType::Bieup,
+        Some('ㅅ') if ends_with(&SIOT_IRREGULAR) => ConjugationType::Siot,
+        Some('ㅎ') if !ends_with(&HIEUT_REGULAR) => ConjugationType::Hieut,
+        None if stem == "푸" => ConjugationType::U,
+        None if stem.ends_with('르') && stem.chars().count() > 1 => {
+            if ends_with(&REO_IRREGULAR) {
+                ConjugationType::Reo
+            } else if ends_with(&REU_REGULAR) {
+                ConjugationType::Regular
+            } else {
+                ConjugationType::Reu
+            }
+        }
+        _ => ConjugationType::Regular,
+    }
+}
+
+/// Attaches an ending to a verb or adjective stem.
+///
+/// Endings are written as in grammar books: "아/어요", "았/었다", "(으)면", "ㅂ/습니다",
+/// "ㄴ/는다" or plain ones such as "고". The stem conjugates as `conjugation_type` says; use
+/// `conjugate_as` to give the type yourself. Vowel harmony (먹어, 잡아), contraction
+/// (보아 → 봐, 되어 → 돼, 하여 → 해), ㅡ dropping (써) and ㄹ dropping (사니까) are applied.
+///
+/// # Arguments
+/// * `stem` - A verb or adjective stem, with or without 다.
+/// * `ending` - The ending to attach.
+///
+/// # Returns
+/// * `String` - The conjugated word.
+///
+/// # Examples
+/// ```
+/// use rustkorean::conjugate;
+///
+/// assert_eq!(conjugate("먹다", "아/어요"), "먹어요");
+/// assert_eq!(conjugate("보다", "아/어요"), "봐요");
+/// assert_eq!(conjugate("하다", "았/었다"), "했다");
+/// assert_eq!(conjugate("듣다", "(으)면"), "들으면");
+/// assert_eq!(conjugate("돕다", "아/어요"), "도와요");
+/// assert_eq!(conjugate("살다", "(으)니까"), "사니까");
+/// assert_eq!(conjugate("가다", "ㅂ/습니다"), "갑니다");
+/// ```
+pub fn conjugate(stem: &str, ending: &str) -> String {
+    conjugate_as(stem, ending, conjugation_type(stem))
+}
+
+/// Attaches an ending to a verb or adjective stem that conjugates as `conjugation` says,
+/// for stems whose type `conjugation_type` gets wrong (묻다 "to bury" is regular).
+///
+/// # Arguments
+/// * `stem` - A verb or adjective stem, with or without 다.
+/// * `ending` - The ending to attach, written as for `conjugate`.
+/// * `conjugation` - The `ConjugationType` of the stem.
+///
+/// # Returns
+/// * `String` - The conjugated word. A stem that is not Hangul is joined to the ending as it is.
+///
+/// # Examples
+/// ```
+/// use rustkorean::conjugation::conjugate_as;
+/// use rustkorean::ConjugationType;
+///
+/// assert_eq!(conjugate_as("묻다", "아/어", ConjugationType::Digeut), "물어");
+/// assert_eq!(conjugate_as("묻다", "아/어", ConjugationType::Regular), "묻어");
+/// ```
+pub fn conjugate_as(stem: &str, ending: &str, conjugation: ConjugationType) -> String {
+    let stem = strip_da(stem);
+    let mut word: Vec<char> = stem.chars().collect();
+    if word.is_empty() || !word.iter().all(|&c| split_one_letter(c).is_some()) {
+        return format!("{}{}", stem, ending);
+    }
+
+    let (kind, rest) = parse_ending(ending);
+    match kind {
+        EndingKind::Harmony => {
+            infinitive(&mut word, conjugation);
+            word.extend(rest.chars());
+        }
+        EndingKind::Past => {
+            infinitive(&mut word, conjugation);
+            set_final(&mut word, Some('ㅆ'));
+            word.extend(rest.chars());
+        }
+        EndingKind::Eu => {
+            attach_eu(&mut word, conjugation, rest);
+        }
+        EndingKind::Batchim(jamo, syllable) => {
+            let (_, _, jong) = last_jamo(&word);
+            if jong == Some('ㄹ') && conjugation == ConjugationType::Regular {
+                set_final(&mut word, None);
+            }
+            if last_jamo(&word).2.is_some() {
+                word.push(syllable);
+            } else {
+                set_final(&mut word, Some(jamo));
+            }
+            word.extend(rest.chars());
+        }
+        EndingKind::Plain => {
+            let (_, _, jong) = last_jamo(&word);
+            if jong == Some('ㄹ') && drops_rieul(rest) {
+                set_final(&mut word, None);
+            }
+            attach_rest(&mut word, rest);
+        }
+    }
+
+    word.into_iter().collect()
+}
+
+fn strip_da(stem: &str) -> &str {
+    match stem.strip_suffix('다') {
+        Some(stripped) if !stripped.is_empty() => stripped,
+        _ => stem,
+    }
+}
+
+/// Splits an ending into how it attaches and the part after the marker.
+fn parse_ending(ending: &str) -> (EndingKind, &str) {
+    const MARKERS: [(&str, EndingKind); 14] = [
+        ("아/어", EndingKind::Harmony),
+        ("어/아", EndingKind::Harmony),
+        ("았/었", EndingKind::Past),
+        ("었/았", EndingKind::Past),
+        ("(으)", EndingKind::Eu),
+        ("ㅂ/습", EndingKind::Batchim('ㅂ', '습')),
+        ("습/ㅂ", EndingKind::Batchim('ㅂ', '습')),
+        ("ㄴ/는", EndingKind::Batchim('ㄴ', '는')),
+        ("는/ㄴ", EndingKind::Batchim('ㄴ', '는')),
+        ("았", EndingKind::Past),
+        ("었", EndingKind::Past),
+        ("아", EndingKind::Harmony),
+        ("어", EndingKind::Harmony),
+        ("으", EndingKind::Eu),
+    ];
+
+    MARKERS
+        .iter()
+        .find_map(|&(marker, kind)| ending.strip_prefix(marker).map(|rest| (kind, rest)))
+        .unwrap_or((EndingKind::Plain, ending))
+}
+
+/// Turns the stem into its -아/어 form (먹어, 봐, 해, 들어, 도와, 파래, 불러).
+fn infinitive(word: &mut Vec<char>, conjugation: ConjugationType) {
+    let (cho, jung, jong) = last_jamo(word);
+    let last = word.len() - 1;
+
+    if word[last] == '하' {
+        word[last] = '해';
+        return;
+    }
+
+    match (conjugation, jong) {
+        (ConjugationType::Digeut, Some('ㄷ')) => {
+            set_final(word, Some('ㄹ'));
+            word.push(harmony_syllable(jung));
+        }
+        (ConjugationType::Bieup, Some('ㅂ')) => {
+            set_final(word, None);
+            // only 돕다 and 곱다 take 와
+            word.push(if jung == 'ㅗ' && word.len() == 1 {
+                '와'
+            } else {
+                '워'
+            });
+        }
+        (ConjugationType::Siot, Some('ㅅ')) => {
+            set_final(word, None);
+            word.push(harmony_syllable(jung));
+        }
+        (ConjugationType::Hieut, Some('ㅎ')) => {
+            // 하얗다 → 하얘, 부옇다 → 부예
+            let vowel = match jung {
+                'ㅑ' => 'ㅒ',
+                'ㅕ' => 'ㅖ',
+                _ => 'ㅐ',
+            };
+            word[last] = syllable(cho, vowel, None);
+        }
+        (ConjugationType::Reu, None) if word.len() > 1 && word[last] == '르' => {
+            let (_, previous_jung, _) = split_one_letter(word[last - 1]).unwrap_or_default();
+            word.pop();
+            set_final(word, Some('ㄹ'));
+            word.push(if bright(previous_jung) { '라' } else { '러' });
+        }
+        (ConjugationType::Reo, None) if word[last] == '르' => word.push('러'),
+        (ConjugationType::U, None) if jung == 'ㅜ' => word[last] = syllable(cho, 'ㅓ', None),
+        // the past marker always takes 어 (갔어, 봤어)
+        (_, Some('ㅆ')) => word.push('어'),
+        (_, Some(_)) => word.push(harmony_syllable(jung)),
+        (_, None) => {
+            let contracted = match jung {
+                // ㅡ drops and the syllable before decides the vowel (바빠, 써)
+                'ㅡ' => Some(match last.checked_sub(1).map(|previous| word[previous]) {
+                    Some(previous)
+                        if split_one_letter(previous).is_some_and(|(_, v, _)| bright(v)) =>
+                    {
+                        'ㅏ'
+                    }
+                    _ => 'ㅓ',
+                }),
+                'ㅗ' => Some('ㅘ'),
+                'ㅜ' => Some('ㅝ'),
+                'ㅣ' => Some('ㅕ'),
+                'ㅚ' => Some('ㅙ'),
+                // 가아 → 가, 서어 → 서, 보내어 → 보내
+                'ㅏ' | 'ㅑ' | 'ㅓ' | 'ㅕ' | 'ㅐ' | 'ㅒ' | 'ㅔ' | 'ㅖ' => Some(jung),
+                _ => None,
+            };
+            match contracted {
+                Some(vowel) => word[last] = syllable(cho, vowel, None),
+                None => word.push(harmony_syllable(jung)),
+            }
+        }
+    }
+}
+
+/// Attaches a -(으) ending such as (으)면, (으)세요 or (으)ㄹ 거예요.
+fn attach_eu(word: &mut Vec<char>, conjugation: ConjugationType, rest: &str) {
+    let (_, _, jong) = last_jamo(word);
+
+    let linking = match (conjugation, jong) {
+        (ConjugationType::Digeut, Some('ㄷ')) => {
+            set_final(word, Some('ㄹ'));
+            Some('으')
+        }
+        (ConjugationType::Bieup, Some('ㅂ')) => {
+            set_final(word, None);
+            Some('우')
+        }
+        (ConjugationType::Siot, Some('ㅅ')) => {
+            set_final(word, None);
+            Some('으')
+        }
+        (ConjugationType::Hieut, Some('ㅎ')) => {
+            set_final(word, None);
+            None
+        }
+        (_, Some('ㄹ')) => {
+            if drops_rieul(rest) {
+                set_final(word, None);
+            }
+            None
+        }
+        (_, Some(_)) => Some('으'),
+        (_, None) => None,
+    };
+
+    word.extend(linking);
+    attach_rest(word, rest);
+}
+
+/// Appends the rest of an ending, making a leading consonant jamo the final of the last syllable.
+fn attach_rest(word: &mut Vec<char>, rest: &str) {
+    let mut chars = rest.chars();
+    if let Some(first) = chars.next() {
+        if is_final_jamo(first) && last_jamo(word).2.is_none() {
+            set_final(word, Some(first));
+            word.extend(chars);
+            return;
+        }
+    }
+    word.extend(rest.chars());
+}
+
+/// ㄹ of a stem drops before ㄴ, ㅂ and ㅅ (사는, 삽니다, 사세요).
+fn drops_rieul(rest: &str) -> bool {
+    let Some(first) = rest.chars().next() else {
+        return false;
+    };
+    let initial = split_one_letter(first).map_or(first, |(cho, _, _)| cho);
+    matches!(initial, 'ㄴ' | 'ㅂ' | 'ㅅ' | 'ㄹ')
+}
+
+fn is_final_jamo(character: char) -> bool {
+    matches!(character, 'ㄱ'..='ㅎ') && !matches!(character, 'ㄸ' | 'ㅃ' | 'ㅉ')
+}
+
+/// Vowels that take -아 rather than -어.
+fn bright(jung: char) -> bool {
+    matches!(jung, 'ㅏ' | 'ㅑ' | 'ㅗ')
+}
+
+fn harmony_syllable(jung: char) -> char {
+    if bright(jung) {
+        '아'
+    } else {
+        '어'
+    }
+}
+
+fn last_jamo(word: &[char]) -> (char, char, Option<char>) {
+    word.last()
+        .and_then(|&last| split_one_letter(last))
+        .unwrap_or_default()
+}
+
+fn set_final(word: &mut [char], jong: Option<char>) {
+    let (cho, jung, _) = last_jamo(word);
+    if let Some(last) = word.last_mut() {
+        *last = syllable(cho, jung, jong);
+    }
+}
+
+pub(crate) fn syllable(cho: char, jung: char, jong: Option<char>) -> char {
+    let mut jamo = format!("{}{}", cho, jung);
+    jamo.extend(jong);
+    make_one_letter(jamo)
+}
@@ -22,3 +22,102 @@ pub enum KoreanType {
     ComplexVowel,     // Complex vowels (복합모음)
     Unknown,          // For characters that do not fit in the above categories (알 수 없는 유형)
 }
+
+/// Represents the Hangul keyboard layouts that keystrokes can be interpreted with.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum KeyboardLayout {
+    Dubeolsik,    // Standard 2-set layout (두벌식 표준)
+    Sebeolsik390, // Gong Byeong-u 3-set layout (세벌식 390)
+}
+
+/// Represents the phone keypad layouts supported by `KeypadInput`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum KeypadLayout {
+    Cheonjiin, // Vowels built from ㆍ, ㅡ, ㅣ strokes (천지인)
+    Naratgeul, // Stroke-add and double keys (나랏글)
+    Sky,       // Every button cycles through its jamo (스카이/베가)
+}
+
+/// Represents a single input event on a phone keypad.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum KeypadEvent {
+    Button(char), // A keypad button such as '1'..'9', '0', '*' or '#'
+    Next,         // Timeout or the "next" key, ends consonant cycling (다음)
+    Backspace,    // Deletes the last jamo or vowel stroke (지우기)
+}
+
+/// Represents where hyphens are placed between the syllables of a romanized word.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Hyphenation {
+    None,      // No hyphens (Jungang)
+    Ambiguous, // Only where the syllable boundary could be misread (Jung-ang)
+    Syllables, // Between every syllable (Jung-ang-ro)
+}
+
+/// Represents how the syllables of a romanized given name are separated.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum NameSeparator {
+    None,   // Written together (Jihye)
+    Hyphen, // Joined by a hyphen (Ji-hye)
+    Space,  // Each syllable as its own word (Ji Hye)
+}
+
+/// Represents a rule of the standard pronunciation (표준 발음법) applied by `pronounce_with_trace`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SoundRule {
+    Neutralization,    // Final reduced to its representative sound (제9항 받침 대표음)
+    CompoundFinal,     // Compound final reduced to one consonant (제10항, 제11항 겹받침)
+    HieutFinal,        // ㅎ final dropped, turned into ㄴ or tensing ㅅ (제12항 ㅎ 받침)
+    Aspiration,        // ㄱ ㄷ ㅂ ㅈ and ㅎ merged into ㅋ ㅌ ㅍ ㅊ (제12항 거센소리되기)
+    Linking,           // Final moved to an empty initial (제13항, 제14항 연음)
+    WordLinking,       // Final moved to the next word (제15항 연음)
+    Palatalization,    // ㄷ ㅌ before 이 turned into ㅈ ㅊ (제17항 구개음화)
+    Nasalization,      // Stop final before ㄴ ㅁ turned nasal (제18항 비음화)
+    RieulNasalization, // ㄹ after ㄱ ㄷ ㅂ ㅁ ㅇ turned into ㄴ (제19항 ㄹ의 비음화)
+    Liquidization,     // ㄴ next to ㄹ turned into ㄹ (제20항 유음화)
+    Tensification,     // Initial tensed after a stop (제23항 된소리되기)
+    NieunInsertion,    // ㄴ added before 이 야 여 요 유 in a compound (제29항 ㄴ 첨가)
+}
+
+/// Represents where a Korean word comes from, which decides whether a compound takes 사이시옷.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum WordOrigin {
+    Native,     // Native Korean word (고유어)
+    SinoKorean, // Word made of Chinese characters (한자어)
+    Loanword,   // Word borrowed from another language (외래어)
+}
+
+/// Represents a pair of particle (조사) forms chosen by whether the word before ends in a final consonant.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum JosaPair {
+    EunNeun, // Topic particle (은/는)
+    IGa,     // Subject particle (이/가)
+    EulReul, // Object particle (을/를)
+    GwaWa,   // Conjunctive particle (과/와)
+    EuroRo,  // Directional particle, 로 after ㄹ too ((으)로)
+    AYa,     // Vocative particle (아/야)
+    IyeoYeo, // Literary vocative particle ((이)여)
+    Copula,  // Copula ((이)다)
+}
+
+/// Represents how a verb or adjective stem changes when an ending is attached.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ConjugationType {
+    Regular, // Regular, including ㄹ and ㅡ dropping (규칙 활용)
+    Digeut,  // ㄷ becomes ㄹ before a vowel, 듣다 → 들어 (ㄷ 불규칙)
+    Bieup,   // ㅂ becomes 우 or 오, 돕다 → 도와 (ㅂ 불규칙)
+    Siot,    // ㅅ drops before a vowel, 낫다 → 나아 (ㅅ 불규칙)
+    Hieut,   // ㅎ drops and the vowel changes, 파랗다 → 파래 (ㅎ 불규칙)
+    Reu,     // 르 becomes ㄹㄹ, 부르다 → 불러 (르 불규칙)
+    Reo,     // 어 becomes 러, 푸르다 → 푸르러 (러 불규칙)
+    U,       // ㅜ drops, 푸다 → 퍼 (우 불규칙)
+}
+
+/// Represents the speech level of a sentence ending.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SpeechLevel {
+    Formal, // 합니다, 합니까 (합쇼체)
+    Polite, // 해요 (해요체)
+    Casual, // 해, 이야 (해체, 반말)
+    Plain,  // 한다, 하니 (해라체)
+}
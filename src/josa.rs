@@ -0,0 +1,417 @@
+use crate::compose_korean::split_one_letter;
+use crate::enums::JosaPair;
+
+/// Attaches the form of a particle (조사) that fits the final consonant of a word.
+///
+/// The form is chosen by the final consonant (받침) of the last syllable: 은, 이, 을, 과, 으로,
+/// 아, 이여 and 이다 after a final consonant, 는, 가, 를, 와, 로, 야, 여 and 다 after a vowel.
+/// (으)로 takes 로 after ㄹ as well (서울로). Words ending in digits or Latin letters are
+/// judged by how they are read aloud: numbers in sino-Korean (15 → 십오), capital letters by
+/// their names (SQL → 에스큐엘) and English words by their loanword spelling (game → 게임).
+/// When the reading cannot be told, both forms are written, as in "은(는)".
+///
+/// # Arguments
+/// * `word` - The word the particle follows.
+/// * `pair` - The `JosaPair` to choose from.
+///
+/// # Returns
+/// * `String` - The word followed by the particle.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{josa, JosaPair};
+///
+/// assert_eq!(josa("사과", JosaPair::EulReul), "사과를");
+/// assert_eq!(josa("책", JosaPair::EulReul), "책을");
+/// assert_eq!(josa("서울", JosaPair::EuroRo), "서울로");
+/// assert_eq!(josa("부산", JosaPair::EuroRo), "부산으로");
+/// assert_eq!(josa("철수", JosaPair::AYa), "철수야");
+/// assert_eq!(josa("학생", JosaPair::Copula), "학생이다");
+/// assert_eq!(josa("iPhone 15", JosaPair::IGa), "iPhone 15가");
+/// assert_eq!(josa("SQL", JosaPair::EulReul), "SQL을");
+/// ```
+pub fn josa(word: &str, pair: JosaPair) -> String {
+    format!("{}{}", word, particle(word, pair))
+}
+
+/// Ways a particle pair can be written after a placeholder in a `josa_format` template.
+const TEMPLATE_PAIRS: [(&str, JosaPair); 23] = [
+    ("은/는", JosaPair::EunNeun),
+    ("는/은", JosaPair::EunNeun),
+    ("은(는)", JosaPair::EunNeun),
+    ("이/가", JosaPair::IGa),
+    ("가/이", JosaPair::IGa),
+    ("이(가)", JosaPair::IGa),
+    ("을/를", JosaPair::EulReul),
+    ("를/을", JosaPair::EulReul),
+    ("을(를)", JosaPair::EulReul),
+    ("과/와", JosaPair::GwaWa),
+    ("와/과", JosaPair::GwaWa),
+    ("과(와)", JosaPair::GwaWa),
+    ("으로/로", JosaPair::EuroRo),
+    ("로/으로", JosaPair::EuroRo),
+    ("(으)로", JosaPair::EuroRo),
+    ("아/야", JosaPair::AYa),
+    ("야/아", JosaPair::AYa),
+    ("아(야)", JosaPair::AYa),
+    ("이여/여", JosaPair::IyeoYeo),
+    ("여/이여", JosaPair::IyeoYeo),
+    ("(이)여", JosaPair::IyeoYeo),
+    ("이다/다", JosaPair::Copula),
+    ("(이)다", JosaPair::Copula),
+];
+
+/// Fills the placeholders of a template and resolves the particle pair after each one.
+///
+/// A placeholder is a name in braces, such as `{name}`. When a particle pair written as
+/// "이/가", "은(는)" or "(으)로" follows a placeholder, it is replaced by the form that fits the
+/// value, chosen as `josa` does. Placeholders without a value are left as they are, and `{{`
+/// and `}}` stand for literal braces.
+///
+/// # Arguments
+/// * `template` - The template, such as a message from a translation file.
+/// * `values` - The value of each placeholder, as (name, value) pairs.
+///
+/// # Returns
+/// * `String` - The filled in message.
+///
+/// # Examples
+/// ```
+/// use rustkorean::josa_format;
+///
+/// let template = "{name}이/가 {item}을/를 샀습니다";
+/// assert_eq!(
+///     josa_format(template, &[("name", "철수"), ("item", "책")]),
+///     "철수가 책을 샀습니다"
+/// );
+/// assert_eq!(
+///     josa_format(template, &[("name", "민준"), ("item", "SQL 책")]),
+///     "민준이 SQL 책을 샀습니다"
+/// );
+/// assert_eq!(josa_format("{city}(으)로 가요", &[("city", "서울")]), "서울로 가요");
+/// ```
+pub fn josa_format(template: &str, values: &[(&str, &str)]) -> String {
+    let mut result = String::new();
+    let mut rest = template;
+
+    while let Some(index) = rest.find(['{', '}']) {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        if let Some(after) = rest.strip_prefix("{{").or_else(|| rest.strip_prefix("}}")) {
+            result.push_str(&rest[..1]);
+            rest = after;
+            continue;
+        }
+
+        let value = rest.strip_prefix('{').and_then(|inside| {
+            let (name, after) = inside.split_once('}')?;
+            let &(_, value) = values.iter().find(|&&(key, _)| key == name)?;
+            Some((value, after))
+        });
+        let Some((value, after)) = value else {
+            result.push_str(&rest[..1]);
+            rest = &rest[1..];
+            continue;
+        };
+
+        result.push_str(value);
+        rest = after;
+        let pair = TEMPLATE_PAIRS
+            .iter()
+            .filter(|(written, _)| rest.starts_with(written))
+            .max_by_key(|(written, _)| written.len());
+        if let Some(&(written, pair)) = pair {
+            result.push_str(particle(value, pair));
+            rest = &rest[written.len()..];
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// What a particle form needs before it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Follows {
+    /// A final consonant (은, 이, 을).
+    Consonant,
+    /// A vowel (는, 가, 를).
+    Vowel,
+    /// A final consonant other than ㄹ (으로).
+    ConsonantButRieul,
+    /// A vowel or ㄹ (로).
+    VowelOrRieul,
+    /// Anything (에서, 도).
+    Any,
+}
+
+/// Particles that end an eojeol: case and topic markers and the like (학교에서는, 친구가).
+/// 나 after a vowel is left out, as too many nouns end in it (하나, 바나나).
+const OUTER_PARTICLES: [(&str, Follows); 20] = [
+    ("이라도", Follows::Consonant),
+    ("라도", Follows::Vowel),
+    ("이든지", Follows::Consonant),
+    ("든지", Follows::Vowel),
+    ("이나", Follows::Consonant),
+    ("은", Follows::Consonant),
+    ("는", Follows::Vowel),
+    ("이", Follows::Consonant),
+    ("가", Follows::Vowel),
+    ("을", Follows::Consonant),
+    ("를", Follows::Vowel),
+    ("아", Follows::Consonant),
+    ("야", Follows::Vowel),
+    ("조차", Follows::Any),
+    ("마저", Follows::Any),
+    ("마다", Follows::Any),
+    ("밖에", Follows::Any),
+    ("도", Follows::Any),
+    ("만", Follows::Any),
+    ("의", Follows::Any),
+];
+
+/// Words that end like a noun with 이, 가, 을 or 를 but are kept whole.
+const WHOLE_NOUNS: [&str; 13] = [
+    "고양이",
+    "어린이",
+    "원숭이",
+    "호랑이",
+    "젊은이",
+    "늙은이",
+    "목걸이",
+    "귀걸이",
+    "지팡이",
+    "놀이",
+    "휴가",
+    "요가",
+    "노를",
+];
+
+/// Particles that can come before an outer one: place, direction, company and comparison
+/// (학교에서는, 친구와도).
+const INNER_PARTICLES: [(&str, Follows); 25] = [
+    ("으로부터", Follows::ConsonantButRieul),
+    ("로부터", Follows::VowelOrRieul),
+    ("으로서", Follows::ConsonantButRieul),
+    ("로서", Follows::VowelOrRieul),
+    ("으로써", Follows::ConsonantButRieul),
+    ("로써", Follows::VowelOrRieul),
+    ("으로", Follows::ConsonantButRieul),
+    ("로", Follows::VowelOrRieul),
+    ("이랑", Follows::Consonant),
+    ("랑", Follows::Vowel),
+    ("과", Follows::Consonant),
+    ("와", Follows::Vowel),
+    ("에게서", Follows::Any),
+    ("한테서", Follows::Any),
+    ("에서", Follows::Any),
+    ("에게", Follows::Any),
+    ("한테", Follows::Any),
+    ("께서", Follows::Any),
+    ("까지", Follows::Any),
+    ("부터", Follows::Any),
+    ("보다", Follows::Any),
+    ("처럼", Follows::Any),
+    ("하고", Follows::Any),
+    ("에", Follows::Any),
+    ("께", Follows::Any),
+];
+
+/// Strips the particles (조사) off the end of an eojeol, for indexing words by their stem.
+///
+/// At most one particle such as 은/는, 이/가, 을/를, 도 or 의 is taken off the end, and then at
+/// most one such as 에서, (으)로, 와/과 or 까지 before it. A particle with two forms is only
+/// stripped when its form agrees with the final consonant of what is left, so 사과를 becomes
+/// 사과 but 노을 stays whole (을 does not follow a vowel). 이, 가, 을 and 를 are also kept on a
+/// short list of words that only look like a noun with a particle (고양이, 휴가, 노를). This is
+/// a light stemmer: other nouns that merely end like a particle (포도) lose their last syllable
+/// too.
+///
+/// # Arguments
+/// * `eojeol` - A word with its particles, as written between spaces.
+///
+/// # Returns
+/// * `(&str, Vec<&str>)` - The stem and the particles stripped from it, in the order written.
+///
+/// # Examples
+/// ```
+/// use rustkorean::strip_josa;
+///
+/// assert_eq!(strip_josa("학교에서는"), ("학교", vec!["에서", "는"]));
+/// assert_eq!(strip_josa("사과를"), ("사과", vec!["를"]));
+/// assert_eq!(strip_josa("서울로"), ("서울", vec!["로"]));
+/// assert_eq!(strip_josa("노을"), ("노을", vec![]));
+/// assert_eq!(strip_josa("노를"), ("노를", vec![]));
+/// assert_eq!(strip_josa("학교"), ("학교", vec![]));
+/// ```
+pub fn strip_josa(eojeol: &str) -> (&str, Vec<&str>) {
+    let mut stem = eojeol;
+    let mut particles = Vec::new();
+
+    for table in [&OUTER_PARTICLES[..], &INNER_PARTICLES[..]] {
+        let stripped = table.iter().find_map(|&(particle, follows)| {
+            let rest = stem.strip_suffix(particle)?;
+            (!rest.is_empty() && agrees(rest, follows) && !is_whole_noun(stem, particle))
+                .then_some((rest, particle))
+        });
+        if let Some((rest, particle)) = stripped {
+            stem = rest;
+            particles.insert(0, &eojeol[rest.len()..rest.len() + particle.len()]);
+        }
+    }
+
+    (stem, particles)
+}
+
+/// Whether `word` is listed as a whole word rather than a noun with the case particle 이, 가,
+/// 을 or 를 (고양이, 휴가).
+fn is_whole_noun(word: &str, particle: &str) -> bool {
+    matches!(particle, "이" | "가" | "을" | "를") && WHOLE_NOUNS.contains(&word)
+}
+
+/// Whether a particle form may follow the word, by the word's final consonant. Words whose
+/// reading cannot be told take any form.
+fn agrees(word: &str, follows: Follows) -> bool {
+    let Some(jong) = final_consonant(word) else {
+        return true;
+    };
+    match follows {
+        Follows::Consonant => jong.is_some(),
+        Follows::Vowel => jong.is_none(),
+        Follows::ConsonantButRieul => jong.is_some() && jong != Some('ㄹ'),
+        Follows::VowelOrRieul => jong.is_none() || jong == Some('ㄹ'),
+        Follows::Any => true,
+    }
+}
+
+/// Returns the form of the particle that fits `word`, without the word.
+pub(crate) fn particle(word: &str, pair: JosaPair) -> &'static str {
+    let (consonant, vowel, both) = forms(pair);
+
+    match final_consonant(word) {
+        Some(Some('ㄹ')) if pair == JosaPair::EuroRo => vowel,
+        Some(Some(_)) => consonant,
+        Some(None) => vowel,
+        None => both,
+    }
+}
+
+/// The forms of a particle: after a final consonant, after a vowel, and both together.
+fn forms(pair: JosaPair) -> (&'static str, &'static str, &'static str) {
+    match pair {
+        JosaPair::EunNeun => ("은", "는", "은(는)"),
+        JosaPair::IGa => ("이", "가", "이(가)"),
+        JosaPair::EulReul => ("을", "를", "을(를)"),
+        JosaPair::GwaWa => ("과", "와", "과(와)"),
+        JosaPair::EuroRo => ("으로", "로", "(으)로"),
+        JosaPair::AYa => ("아", "야", "아(야)"),
+        JosaPair::IyeoYeo => ("이여", "여", "(이)여"),
+        JosaPair::Copula => ("이다", "다", "(이)다"),
+    }
+}
+
+/// Finals of the sino-Korean readings of the digits 0 to 9 (영, 일, 이, 삼, 사, 오, 육, 칠, 팔, 구).
+const DIGIT_FINALS: [Option<char>; 10] = [
+    Some('ㅇ'),
+    Some('ㄹ'),
+    None,
+    Some('ㅁ'),
+    None,
+    None,
+    Some('ㄱ'),
+    Some('ㄹ'),
+    Some('ㄹ'),
+    None,
+];
+
+/// Finals of the units read after trailing zeros: 십, 백, 천 and then 만, 억, 조, 경.
+const TEN_FINALS: [Option<char>; 3] = [Some('ㅂ'), Some('ㄱ'), Some('ㄴ')];
+const MYRIAD_FINALS: [Option<char>; 4] = [Some('ㄴ'), Some('ㄱ'), None, Some('ㅇ')];
+
+/// Returns the final consonant `word` ends in when read aloud, `Some(None)` if it ends in a
+/// vowel, or `None` if its reading cannot be told.
+///
+/// Hangul is read as written, trailing digits in sino-Korean (15 → 십오, 100 → 백), a run of
+/// capital letters as letter names (SQL → 에스큐엘) and other Latin words by their usual
+/// loanword endings (game → 게임, book → 북, phone → 폰). Closing quotes and brackets are skipped.
+fn final_consonant(word: &str) -> Option<Option<char>> {
+    let word = word.trim_end_matches([')', ']', '}', '"', '\'', '’', '”', '」', '』', '》', '〉']);
+    let last = word.chars().last()?;
+
+    if let Some((_, _, jong)) = split_one_letter(last) {
+        return Some(jong);
+    }
+    if last.is_ascii_digit() {
+        return number_final(word);
+    }
+    if last.is_ascii_alphabetic() {
+        let start = word
+            .rfind(|character: char| !character.is_ascii_alphabetic())
+            .map_or(0, |index| index + 1);
+        let letters = &word[start..];
+        return Some(if letters.chars().all(|c| c.is_ascii_uppercase()) {
+            letter_name_final(last)
+        } else {
+            english_final(&letters.to_ascii_lowercase())
+        });
+    }
+    None
+}
+
+/// Reads the trailing number of `word` in sino-Korean, ignoring thousands separators.
+fn number_final(word: &str) -> Option<Option<char>> {
+    let digits: Vec<u32> = word
+        .chars()
+        .rev()
+        .take_while(|character| character.is_ascii_digit() || *character == ',')
+        .filter_map(|character| character.to_digit(10))
+        .collect();
+
+    let zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    if zeros == digits.len() {
+        return Some(DIGIT_FINALS[0]);
+    }
+    if zeros == 0 {
+        return Some(DIGIT_FINALS[digits[0] as usize]);
+    }
+    // past 만 the number is read ending in its myriad unit (십조, 백만), not in 십/백/천
+    Some(match zeros {
+        1..=3 => TEN_FINALS[zeros - 1],
+        _ => *MYRIAD_FINALS.get(zeros / 4 - 1)?,
+    })
+}
+
+/// The final of the Korean name of a capital letter: L 엘, M 엠, N 엔, R 알.
+fn letter_name_final(letter: char) -> Option<char> {
+    match letter {
+        'L' | 'R' => Some('ㄹ'),
+        'M' => Some('ㅁ'),
+        'N' => Some('ㄴ'),
+        _ => None,
+    }
+}
+
+/// The final an English word gets when written in Hangul, judged by how it ends.
+fn english_final(word: &str) -> Option<char> {
+    // a silent e after m, n or l leaves that consonant as the final (game, phone, google)
+    let word = match word.strip_suffix('e') {
+        Some(stem) if stem.ends_with(['m', 'n', 'l']) => stem,
+        _ => word,
+    };
+    let before_last = word.chars().rev().nth(1);
+    let after_vowel = before_last.is_some_and(|c| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u'));
+
+    if word.ends_with("ng") {
+        return Some('ㅇ');
+    }
+    match word.chars().last()? {
+        'm' => Some('ㅁ'),
+        'n' => Some('ㄴ'),
+        'l' => Some('ㄹ'),
+        'c' | 'k' => Some('ㄱ'),
+        'b' | 'p' => Some('ㅂ'),
+        't' if after_vowel => Some('ㅅ'),
+        _ => None,
+    }
+}
@@ -0,0 +1,369 @@
+use crate::compose_korean::{
+    compose_korean, split_double_consonant, split_double_vowel, split_one_letter, tense_consonant,
+    JamoComposer,
+};
+use crate::enums::KeyboardLayout;
+use crate::{check_korean, first_letter_check, last_letter_check, middle_letter_check};
+use std::time::Duration;
+
+/// The position in a syllable that a Sebeolsik key types.
+#[derive(PartialEq, Clone, Copy)]
+enum JamoRole {
+    Initial,
+    Medial,
+    Final,
+}
+
+/// Key map of the Dubeolsik layout: (English key, jamo). Unlike `english_input_to_korean`, it
+/// includes the vowels on o and p and the tense consonants typed with Shift.
+const DUBEOLSIK: [(char, char); 33] = [
+    ('r', 'ㄱ'),
+    ('s', 'ㄴ'),
+    ('e', 'ㄷ'),
+    ('f', 'ㄹ'),
+    ('a', 'ㅁ'),
+    ('q', 'ㅂ'),
+    ('t', 'ㅅ'),
+    ('d', 'ㅇ'),
+    ('w', 'ㅈ'),
+    ('c', 'ㅊ'),
+    ('z', 'ㅋ'),
+    ('x', 'ㅌ'),
+    ('v', 'ㅍ'),
+    ('g', 'ㅎ'),
+    ('k', 'ㅏ'),
+    ('o', 'ㅐ'),
+    ('i', 'ㅑ'),
+    ('j', 'ㅓ'),
+    ('p', 'ㅔ'),
+    ('u', 'ㅕ'),
+    ('h', 'ㅗ'),
+    ('y', 'ㅛ'),
+    ('n', 'ㅜ'),
+    ('b', 'ㅠ'),
+    ('m', 'ㅡ'),
+    ('l', 'ㅣ'),
+    ('Q', 'ㅃ'),
+    ('W', 'ㅉ'),
+    ('E', 'ㄸ'),
+    ('R', 'ㄲ'),
+    ('T', 'ㅆ'),
+    ('O', 'ㅒ'),
+    ('P', 'ㅖ'),
+];
+
+/// Key map of the Sebeolsik 390 layout: (English key, position in the syllable, jamo).
+/// Keys that are not listed here type their own character.
+const SEBEOLSIK_390: [(char, JamoRole, char); 58] = [
+    // initial consonants (초성)
+    ('k', JamoRole::Initial, 'ㄱ'),
+    ('h', JamoRole::Initial, 'ㄴ'),
+    ('u', JamoRole::Initial, 'ㄷ'),
+    ('y', JamoRole::Initial, 'ㄹ'),
+    ('i', JamoRole::Initial, 'ㅁ'),
+    (';', JamoRole::Initial, 'ㅂ'),
+    ('n', JamoRole::Initial, 'ㅅ'),
+    ('j', JamoRole::Initial, 'ㅇ'),
+    ('l', JamoRole::Initial, 'ㅈ'),
+    ('o', JamoRole::Initial, 'ㅊ'),
+    ('0', JamoRole::Initial, 'ㅋ'),
+    ('\'', JamoRole::Initial, 'ㅌ'),
+    ('p', JamoRole::Initial, 'ㅍ'),
+    ('m', JamoRole::Initial, 'ㅎ'),
+    // medial vowels (중성)
+    ('f', JamoRole::Medial, 'ㅏ'),
+    ('r', JamoRole::Medial, 'ㅐ'),
+    ('6', JamoRole::Medial, 'ㅑ'),
+    ('G', JamoRole::Medial, 'ㅒ'),
+    ('t', JamoRole::Medial, 'ㅓ'),
+    ('c', JamoRole::Medial, 'ㅔ'),
+    ('e', JamoRole::Medial, 'ㅕ'),
+    ('7', JamoRole::Medial, 'ㅖ'),
+    ('v', JamoRole::Medial, 'ㅗ'),
+    ('4', JamoRole::Medial, 'ㅛ'),
+    ('b', JamoRole::Medial, 'ㅜ'),
+    ('5', JamoRole::Medial, 'ㅠ'),
+    ('g', JamoRole::Medial, 'ㅡ'),
+    ('8', JamoRole::Medial, 'ㅢ'),
+    ('d', JamoRole::Medial, 'ㅣ'),
+    ('/', JamoRole::Medial, 'ㅗ'),
+    ('9', JamoRole::Medial, 'ㅜ'),
+    // final consonants (종성)
+    ('x', JamoRole::Final, 'ㄱ'),
+    ('!', JamoRole::Final, 'ㄲ'),
+    ('V', JamoRole::Final, 'ㄳ'),
+    ('s', JamoRole::Final, 'ㄴ'),
+    ('E', JamoRole::Final, 'ㄵ'),
+    ('S', JamoRole::Final, 'ㄶ'),
+    ('A', JamoRole::Final, 'ㄷ'),
+    ('w', JamoRole::Final, 'ㄹ'),
+    ('@', JamoRole::Final, 'ㄺ'),
+    ('F', JamoRole::Final, 'ㄻ'),
+    ('D', JamoRole::Final, 'ㄼ'),
+    ('T', JamoRole::Final, 'ㄽ'),
+    ('%', JamoRole::Final, 'ㄾ'),
+    ('$', JamoRole::Final, 'ㄿ'),
+    ('R', JamoRole::Final, 'ㅀ'),
+    ('z', JamoRole::Final, 'ㅁ'),
+    ('3', JamoRole::Final, 'ㅂ'),
+    ('X', JamoRole::Final, 'ㅄ'),
+    ('q', JamoRole::Final, 'ㅅ'),
+    ('2', JamoRole::Final, 'ㅆ'),
+    ('a', JamoRole::Final, 'ㅇ'),
+    ('#', JamoRole::Final, 'ㅈ'),
+    ('Z', JamoRole::Final, 'ㅊ'),
+    ('C', JamoRole::Final, 'ㅋ'),
+    ('W', JamoRole::Final, 'ㅌ'),
+    ('Q', JamoRole::Final, 'ㅍ'),
+    ('1', JamoRole::Final, 'ㅎ'),
+];
+
+/// Converts Hangul text into the English keys that type it on the given keyboard layout.
+///
+/// Compound vowels and compound final consonants are split into the keys that build them
+/// (e.g. ㅘ is typed as ㅗ then ㅏ). Characters that are not Hangul are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` to convert.
+/// * `layout` - The `KeyboardLayout` the text is typed with.
+///
+/// # Returns
+/// * `String` - The keystrokes that type the text.
+///
+/// # Examples
+/// ```
+/// use rustkorean::keyboard::korean_to_keystrokes;
+/// use rustkorean::KeyboardLayout;
+///
+/// assert_eq!(korean_to_keystrokes("한글", KeyboardLayout::Dubeolsik), "gksrmf");
+/// assert_eq!(korean_to_keystrokes("한글", KeyboardLayout::Sebeolsik390), "mfskgw");
+/// ```
+pub fn korean_to_keystrokes(text: &str, layout: KeyboardLayout) -> String {
+    let mut result = String::new();
+
+    for character in text.chars() {
+        if let Some((cho, jung, jong)) = split_one_letter(character) {
+            push_keys(&mut result, layout, JamoRole::Initial, cho);
+            push_keys(&mut result, layout, JamoRole::Medial, jung);
+            if let Some(jong) = jong {
+                push_keys(&mut result, layout, JamoRole::Final, jong);
+            }
+        } else if first_letter_check(character) {
+            push_keys(&mut result, layout, JamoRole::Initial, character);
+        } else if last_letter_check(character) {
+            push_keys(&mut result, layout, JamoRole::Final, character);
+        } else if middle_letter_check(character) {
+            push_keys(&mut result, layout, JamoRole::Medial, character);
+        } else {
+            result.push(character);
+        }
+    }
+
+    result
+}
+
+/// Runs English keystrokes through the input automaton of the given keyboard layout.
+///
+/// # Arguments
+/// * `keys` - A `&str` of keys as they were pressed.
+/// * `layout` - The `KeyboardLayout` the keys are interpreted with.
+///
+/// # Returns
+/// * `String` - The composed Hangul text.
+///
+/// # Examples
+/// ```
+/// use rustkorean::keyboard::keystrokes_to_korean;
+/// use rustkorean::KeyboardLayout;
+///
+/// assert_eq!(keystrokes_to_korean("dkssudgktpdy", KeyboardLayout::Dubeolsik), "안녕하세요");
+/// assert_eq!(keystrokes_to_korean("mfskgw", KeyboardLayout::Sebeolsik390), "한글");
+/// ```
+pub fn keystrokes_to_korean(keys: &str, layout: KeyboardLayout) -> String {
+    if layout == KeyboardLayout::Dubeolsik {
+        return compose_korean(keys.chars().map(dubeolsik_jamo).collect());
+    }
+
+    // Sebeolsik keys already say where in the syllable their jamo goes
+    let mut composer = JamoComposer::default();
+    for key in keys.chars() {
+        match SEBEOLSIK_390.iter().find(|e| e.0 == key) {
+            Some(&(_, JamoRole::Initial, jamo)) => composer.push_initial(jamo),
+            Some(&(_, JamoRole::Medial, jamo)) => composer.push_medial(jamo),
+            Some(&(_, JamoRole::Final, jamo)) => composer.push_final(jamo),
+            None => composer.push_other(key),
+        }
+    }
+    composer.finish()
+}
+
+/// Re-interprets Hangul text that was typed on one keyboard layout while the input method
+/// was set to another.
+///
+/// The keystrokes behind each word with Hangul in `text` are recovered under the `typed_as`
+/// layout and composed again under the `intended` layout. Words without Hangul and Latin
+/// letters, which were typed with the input method off, are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` produced by the wrong layout.
+/// * `typed_as` - The `KeyboardLayout` the input method was set to.
+/// * `intended` - The `KeyboardLayout` the user was actually typing on.
+///
+/// # Returns
+/// * `String` - The text the user meant to type.
+///
+/// # Examples
+/// ```
+/// use rustkorean::keyboard::convert_layout;
+/// use rustkorean::KeyboardLayout;
+///
+/// // "한글" typed by a Sebeolsik 390 user on a machine set to Dubeolsik
+/// let garbled = "ㅡㄹ낳ㅈ";
+/// let fixed = convert_layout(garbled, KeyboardLayout::Dubeolsik, KeyboardLayout::Sebeolsik390);
+/// assert_eq!(fixed, "한글");
+/// ```
+pub fn convert_layout(text: &str, typed_as: KeyboardLayout, intended: KeyboardLayout) -> String {
+    let convert = |run: &str| keystrokes_to_korean(&korean_to_keystrokes(run, typed_as), intended);
+    let mut result = String::new();
+
+    for (index, word) in text.split(' ').enumerate() {
+        if index > 0 {
+            result.push(' ');
+        }
+        // words without Hangul, and Latin letters anywhere, were typed with the input method off
+        if !word.chars().any(check_korean) {
+            result.push_str(word);
+            continue;
+        }
+        let mut run = String::new();
+        for character in word.chars() {
+            if character.is_ascii_alphabetic() {
+                result.push_str(&convert(&run));
+                run.clear();
+                result.push(character);
+            } else {
+                run.push(character);
+            }
+        }
+        result.push_str(&convert(&run));
+    }
+
+    result
+}
+
+/// Counts the keystrokes (타수) needed to type the text on the given keyboard layout.
+///
+/// Compound vowels and compound final consonants count one keystroke per key that builds them
+/// (ㅘ = 2, ㄺ = 2 on Dubeolsik), and a key that needs Shift (ㅃ, ㅒ, ㅖ on Dubeolsik) counts
+/// as two keystrokes. Characters that are not Hangul count as the keys that type them.
+///
+/// # Arguments
+/// * `text` - A `&str` to count.
+/// * `layout` - The `KeyboardLayout` the text is typed with.
+///
+/// # Returns
+/// * `usize` - The number of keystrokes.
+///
+/// # Examples
+/// ```
+/// use rustkorean::keyboard::keystroke_count;
+/// use rustkorean::KeyboardLayout;
+///
+/// assert_eq!(keystroke_count("한글", KeyboardLayout::Dubeolsik), 6);
+/// assert_eq!(keystroke_count("닭", KeyboardLayout::Dubeolsik), 4);
+/// assert_eq!(keystroke_count("빵", KeyboardLayout::Dubeolsik), 4);
+/// ```
+pub fn keystroke_count(text: &str, layout: KeyboardLayout) -> usize {
+    korean_to_keystrokes(text, layout)
+        .chars()
+        .map(|key| if shifted_key(key) { 2 } else { 1 })
+        .sum()
+}
+
+/// Calculates the typing speed in keystrokes per minute (타/분).
+///
+/// # Arguments
+/// * `text` - A `&str` that was typed.
+/// * `layout` - The `KeyboardLayout` the text was typed with.
+/// * `elapsed` - The `Duration` it took to type the text.
+///
+/// # Returns
+/// * `f64` - Keystrokes per minute, or `0.0` if no time has elapsed.
+///
+/// # Examples
+/// ```
+/// use rustkorean::keyboard::keystrokes_per_minute;
+/// use rustkorean::KeyboardLayout;
+/// use std::time::Duration;
+///
+/// let speed = keystrokes_per_minute("안녕하세요", KeyboardLayout::Dubeolsik, Duration::from_secs(3));
+/// assert_eq!(speed, 240.0);
+/// ```
+pub fn keystrokes_per_minute(text: &str, layout: KeyboardLayout, elapsed: Duration) -> f64 {
+    if elapsed.is_zero() {
+        return 0.0;
+    }
+    keystroke_count(text, layout) as f64 * 60.0 / elapsed.as_secs_f64()
+}
+
+fn shifted_key(key: char) -> bool {
+    key.is_ascii_uppercase() || "~!@#$%^&*()_+{}|:\"<>?".contains(key)
+}
+
+fn push_keys(result: &mut String, layout: KeyboardLayout, role: JamoRole, jamo: char) {
+    match layout {
+        KeyboardLayout::Dubeolsik => {
+            match split_double_vowel(jamo).or_else(|| split_double_consonant(jamo)) {
+                Some((first, second)) => {
+                    result.push(dubeolsik_key(first));
+                    result.push(dubeolsik_key(second));
+                }
+                None => result.push(dubeolsik_key(jamo)),
+            }
+        }
+        KeyboardLayout::Sebeolsik390 => {
+            if let Some(key) = sebeolsik_key(role, jamo) {
+                result.push(key);
+            } else if let Some(base) = "ㄱㄷㅂㅅㅈ"
+                .chars()
+                .find(|&base| role == JamoRole::Initial && tense_consonant(base) == Some(jamo))
+            {
+                // tense initials are typed by pressing the plain initial twice
+                let key = sebeolsik_key(role, base).unwrap_or(base);
+                result.push(key);
+                result.push(key);
+            } else if let Some((first, second)) = split_double_vowel(jamo) {
+                // compound vowels start with the dedicated ㅗ, ㅜ combining keys
+                result.push(match first {
+                    'ㅗ' => '/',
+                    'ㅜ' => '9',
+                    _ => sebeolsik_key(role, first).unwrap_or(first),
+                });
+                result.push(sebeolsik_key(role, second).unwrap_or(second));
+            } else {
+                result.push(jamo);
+            }
+        }
+    }
+}
+
+fn dubeolsik_jamo(key: char) -> char {
+    DUBEOLSIK
+        .iter()
+        .find(|&&(entry, _)| entry == key)
+        .map_or(key, |&(_, jamo)| jamo)
+}
+
+fn dubeolsik_key(jamo: char) -> char {
+    DUBEOLSIK
+        .iter()
+        .find(|&&(_, entry)| entry == jamo)
+        .map_or(jamo, |&(key, _)| key)
+}
+
+fn sebeolsik_key(role: JamoRole, jamo: char) -> Option<char> {
+    SEBEOLSIK_390
+        .iter()
+        .find(|&&(_, key_role, key_jamo)| key_role == role && key_jamo == jamo)
+        .map(|&(key, _, _)| key)
+}
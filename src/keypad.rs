@@ -0,0 +1,285 @@
+use crate::classify_korean;
+use crate::compose_korean::{compose_korean, tense_consonant};
+use crate::enums::{KeypadEvent, KeypadLayout, KoreanType};
+
+/// Consonant buttons of the Cheonjiin keypad and the jamo each one cycles through.
+const CHEONJIIN_CONSONANTS: [(char, &[char]); 7] = [
+    ('4', &['ㄱ', 'ㅋ', 'ㄲ']),
+    ('5', &['ㄴ', 'ㄹ']),
+    ('6', &['ㄷ', 'ㅌ', 'ㄸ']),
+    ('7', &['ㅂ', 'ㅍ', 'ㅃ']),
+    ('8', &['ㅅ', 'ㅎ', 'ㅆ']),
+    ('9', &['ㅈ', 'ㅊ', 'ㅉ']),
+    ('0', &['ㅇ', 'ㅁ']),
+];
+
+/// Vowel stroke buttons of the Cheonjiin keypad: ㅣ (사람), ㆍ (하늘), ㅡ (땅).
+const CHEONJIIN_STROKES: [(char, char); 3] = [('1', 'ㅣ'), ('2', 'ㆍ'), ('3', 'ㅡ')];
+
+/// How a vowel grows when another stroke is added: (current, stroke, result).
+/// 'ᆢ' is the intermediate state of two ㆍ strokes.
+const CHEONJIIN_VOWELS: [(char, char, char); 21] = [
+    ('ㅣ', 'ㆍ', 'ㅏ'),
+    ('ㅏ', 'ㆍ', 'ㅑ'),
+    ('ㅏ', 'ㅣ', 'ㅐ'),
+    ('ㅑ', 'ㅣ', 'ㅒ'),
+    ('ㆍ', 'ㆍ', 'ᆢ'),
+    ('ㆍ', 'ㅣ', 'ㅓ'),
+    ('ᆢ', 'ㅣ', 'ㅕ'),
+    ('ㅓ', 'ㅣ', 'ㅔ'),
+    ('ㅕ', 'ㅣ', 'ㅖ'),
+    ('ㆍ', 'ㅡ', 'ㅗ'),
+    ('ᆢ', 'ㅡ', 'ㅛ'),
+    ('ㅗ', 'ㅣ', 'ㅚ'),
+    ('ㅚ', 'ㆍ', 'ㅘ'),
+    ('ㅘ', 'ㅣ', 'ㅙ'),
+    ('ㅡ', 'ㆍ', 'ㅜ'),
+    ('ㅜ', 'ㆍ', 'ㅠ'),
+    ('ㅜ', 'ㅣ', 'ㅟ'),
+    ('ㅠ', 'ㅣ', 'ㅝ'),
+    ('ㅝ', 'ㅣ', 'ㅞ'),
+    ('ㅡ', 'ㅣ', 'ㅢ'),
+    ('ᆢ', 'ㆍ', 'ㆍ'),
+];
+
+/// Plain buttons of the Naratgeul keypad. '*' adds a stroke and '#' doubles a consonant.
+const NARATGEUL_KEYS: [(char, char); 8] = [
+    ('1', 'ㄱ'),
+    ('2', 'ㄴ'),
+    ('4', 'ㄹ'),
+    ('5', 'ㅁ'),
+    ('7', 'ㅅ'),
+    ('8', 'ㅇ'),
+    ('9', 'ㅣ'),
+    ('0', 'ㅡ'),
+];
+
+/// Vowel buttons of the Naratgeul keypad that toggle when pressed again.
+const NARATGEUL_CYCLES: [(char, &[char]); 2] = [('3', &['ㅏ', 'ㅓ']), ('6', &['ㅗ', 'ㅜ'])];
+
+/// What the Naratgeul stroke-add key ('*', 획추가) turns a jamo into.
+const NARATGEUL_STROKES: [(char, char); 12] = [
+    ('ㄱ', 'ㅋ'),
+    ('ㄴ', 'ㄷ'),
+    ('ㄷ', 'ㅌ'),
+    ('ㅁ', 'ㅂ'),
+    ('ㅂ', 'ㅍ'),
+    ('ㅅ', 'ㅈ'),
+    ('ㅈ', 'ㅊ'),
+    ('ㅇ', 'ㅎ'),
+    ('ㅏ', 'ㅑ'),
+    ('ㅓ', 'ㅕ'),
+    ('ㅗ', 'ㅛ'),
+    ('ㅜ', 'ㅠ'),
+];
+
+/// Buttons of the Sky (Vega) keypad and the jamo each one cycles through.
+const SKY_CYCLES: [(char, &[char]); 12] = [
+    ('1', &['ㄱ', 'ㅋ', 'ㄲ']),
+    ('2', &['ㅣ', 'ㅡ', 'ㅢ']),
+    ('3', &['ㅏ', 'ㅑ']),
+    ('4', &['ㄷ', 'ㅌ', 'ㄸ']),
+    ('5', &['ㄴ', 'ㄹ']),
+    ('6', &['ㅓ', 'ㅕ']),
+    ('7', &['ㅁ', 'ㅅ', 'ㅆ']),
+    ('8', &['ㅂ', 'ㅍ', 'ㅃ']),
+    ('9', &['ㅗ', 'ㅛ']),
+    ('*', &['ㅈ', 'ㅊ', 'ㅉ']),
+    ('0', &['ㅇ', 'ㅎ']),
+    ('#', &['ㅜ', 'ㅠ']),
+];
+
+/// A phone keypad input method that turns button events into composed Hangul.
+///
+/// Repeated presses of a cycling button (ㄱ → ㅋ → ㄲ on Cheonjiin, ㅏ → ㅓ on Naratgeul) go
+/// through its jamo until another button or `KeypadEvent::Next` is pressed. The jamo are
+/// composed into syllables with the same rules as a Dubeolsik keyboard, so a final consonant
+/// moves to the next syllable when a vowel follows.
+///
+/// # Examples
+/// ```
+/// use rustkorean::keypad::KeypadInput;
+/// use rustkorean::{KeypadEvent, KeypadLayout};
+///
+/// let mut input = KeypadInput::new(KeypadLayout::Cheonjiin);
+/// // ㅎ (8 8), ㅏ (1 2), ㄴ (5), ㄱ (4), ㅡ (3), ㄹ (5 5)
+/// for button in "881254355".chars() {
+///     input.push(KeypadEvent::Button(button));
+/// }
+/// assert_eq!(input.text(), "한글");
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeypadInput {
+    layout: KeypadLayout,
+    events: Vec<KeypadEvent>,
+}
+
+impl KeypadInput {
+    /// Creates an empty input for the given keypad layout.
+    pub fn new(layout: KeypadLayout) -> Self {
+        KeypadInput {
+            layout,
+            events: Vec::new(),
+        }
+    }
+
+    /// Feeds a keypad event into the input.
+    ///
+    /// `KeypadEvent::Backspace` deletes the last jamo, including every cycling press
+    /// that built it, or undoes the last vowel stroke or Naratgeul '*' / '#' key.
+    pub fn push(&mut self, event: KeypadEvent) {
+        match event {
+            KeypadEvent::Backspace => self.delete(),
+            _ => self.events.push(event),
+        }
+    }
+
+    /// Returns the composed text of every event so far.
+    pub fn text(&self) -> String {
+        let mut jamo = Vec::new();
+        let mut previous = None;
+
+        for &event in &self.events {
+            if let KeypadEvent::Button(button) = event {
+                press(self.layout, &mut jamo, button, previous);
+                previous = Some(button);
+            } else {
+                previous = None;
+            }
+        }
+
+        compose_korean(jamo)
+    }
+
+    /// Removes every event, clearing the text.
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    fn delete(&mut self) {
+        while self.events.last() == Some(&KeypadEvent::Next) {
+            self.events.pop();
+        }
+
+        if let Some(KeypadEvent::Button(button)) = self.events.pop() {
+            if cycle(self.layout, button).is_some() {
+                while self.events.last() == Some(&KeypadEvent::Button(button)) {
+                    self.events.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Returns the jamo a cycling button goes through on the given layout.
+fn cycle(layout: KeypadLayout, button: char) -> Option<&'static [char]> {
+    let cycles: &[(char, &'static [char])] = match layout {
+        KeypadLayout::Cheonjiin => &CHEONJIIN_CONSONANTS,
+        KeypadLayout::Naratgeul => &NARATGEUL_CYCLES,
+        KeypadLayout::Sky => &SKY_CYCLES,
+    };
+    cycles
+        .iter()
+        .find(|&&(key, _)| key == button)
+        .map(|&(_, jamo)| jamo)
+}
+
+/// Applies one button press to the jamo typed so far.
+/// `previous` is the button pressed just before, or `None` after `KeypadEvent::Next`.
+fn press(layout: KeypadLayout, jamo: &mut Vec<char>, button: char, previous: Option<char>) {
+    if let Some(cycle) = cycle(layout, button) {
+        let last = jamo.last().copied();
+        match last.and_then(|last| cycle.iter().position(|&c| c == last)) {
+            Some(index) if previous == Some(button) => {
+                jamo.pop();
+                jamo.push(cycle[(index + 1) % cycle.len()]);
+            }
+            _ => push_jamo(jamo, cycle[0], previous),
+        }
+        return;
+    }
+
+    match layout {
+        KeypadLayout::Cheonjiin => {
+            let Some(&(_, stroke)) = CHEONJIIN_STROKES.iter().find(|&&(key, _)| key == button)
+            else {
+                jamo.push(button);
+                return;
+            };
+            let grown = jamo
+                .last()
+                .filter(|_| previous.is_some())
+                .and_then(|&last| {
+                    CHEONJIIN_VOWELS
+                        .iter()
+                        .find(|&&(from, with, _)| from == last && with == stroke)
+                        .map(|&(_, _, to)| to)
+                });
+            match grown {
+                Some(vowel) => {
+                    jamo.pop();
+                    jamo.push(vowel);
+                }
+                None => jamo.push(stroke),
+            }
+        }
+        KeypadLayout::Naratgeul => match button {
+            '*' => modify_last(jamo, add_stroke),
+            '#' => modify_last(jamo, toggle_tense),
+            _ => match NARATGEUL_KEYS.iter().find(|&&(key, _)| key == button) {
+                Some(&(_, character)) => push_jamo(jamo, character, previous),
+                None => jamo.push(button),
+            },
+        },
+        KeypadLayout::Sky => jamo.push(button),
+    }
+}
+
+/// Pushes a jamo, merging ㅣ into a vowel just typed before it (ㅏ + ㅣ → ㅐ).
+fn push_jamo(jamo: &mut Vec<char>, character: char, previous: Option<char>) {
+    let merged = match (jamo.last(), character) {
+        (Some('ㅏ'), 'ㅣ') => Some('ㅐ'),
+        (Some('ㅓ'), 'ㅣ') => Some('ㅔ'),
+        (Some('ㅑ'), 'ㅣ') => Some('ㅒ'),
+        (Some('ㅕ'), 'ㅣ') => Some('ㅖ'),
+        _ => None,
+    };
+
+    match merged.filter(|_| previous.is_some()) {
+        Some(vowel) => {
+            jamo.pop();
+            jamo.push(vowel);
+        }
+        None => jamo.push(character),
+    }
+}
+
+fn modify_last(jamo: &mut [char], modify: fn(char) -> Option<char>) {
+    if let Some(last) = jamo.last_mut() {
+        if let Some(modified) = modify(*last) {
+            *last = modified;
+        }
+    }
+}
+
+/// Naratgeul stroke-add key: ㄱ → ㅋ, ㄴ → ㄷ → ㅌ, ㅏ → ㅑ and so on.
+fn add_stroke(character: char) -> Option<char> {
+    match classify_korean(character) {
+        KoreanType::Consonant | KoreanType::Vowel => NARATGEUL_STROKES
+            .iter()
+            .find(|&&(from, _)| from == character)
+            .map(|&(_, to)| to),
+        _ => None,
+    }
+}
+
+/// Naratgeul double key: turns a plain consonant into its tense form and back.
+fn toggle_tense(character: char) -> Option<char> {
+    match classify_korean(character) {
+        KoreanType::Consonant => tense_consonant(character),
+        KoreanType::ComplexConsonant => "ㄱㄷㅂㅅㅈ"
+            .chars()
+            .find(|&plain| tense_consonant(plain) == Some(character)),
+        _ => None,
+    }
+}
@@ -1,7 +1,37 @@
 pub mod compose_korean;
+pub mod conjugation;
 pub mod enums;
-pub use compose_korean::{compose_korean, create_double_consonant};
-pub use enums::{KoreanType, SyllableType};
+pub mod josa;
+pub mod keyboard;
+pub mod keypad;
+pub mod morpheme;
+pub mod pronunciation;
+pub mod romanization;
+pub mod slug;
+pub mod speech_level;
+pub use compose_korean::{compose_korean, create_double_consonant, decompose_korean};
+pub use conjugation::conjugate;
+pub use enums::{
+    ConjugationType, Hyphenation, JosaPair, KeyboardLayout, KeypadEvent, KeypadLayout, KoreanType,
+    NameSeparator, SoundRule, SpeechLevel, SyllableType, WordOrigin,
+};
+pub use josa::{josa, josa_format, strip_josa};
+pub use keyboard::{
+    convert_layout, keystroke_count, keystrokes_per_minute, keystrokes_to_korean,
+    korean_to_keystrokes,
+};
+pub use keypad::KeypadInput;
+pub use morpheme::{Lexicon, Morpheme};
+pub use pronunciation::{
+    final_sound, needs_saisiot, pronounce, pronounce_with_trace, representative_final,
+    PronunciationDictionary, SoundChange,
+};
+pub use romanization::{
+    deromanize, from_yale, romanize, romanize_mccune_reischauer, romanize_name, romanize_with,
+    to_cyrillic, to_ipa, to_yale, RomanizeOptions,
+};
+pub use slug::{slugify, slugify_with, SlugOptions};
+pub use speech_level::{change_speech_level, detect_speech_level, set_honorific};
 
 /// Checks if a character is a Korean syllable or a complete Korean character.
 ///
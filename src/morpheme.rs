@@ -0,0 +1,439 @@
+use crate::compose_korean::split_one_letter;
+use crate::conjugation::syllable;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Cost of a word the lexicon does not know, high enough that any reading from the lexicon
+/// is preferred.
+const UNKNOWN_COST: i64 = 10_000;
+
+/// A morpheme with its part-of-speech tag, such as 하/VV or 었/EP.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Morpheme {
+    /// The form of the morpheme, as in the lexicon (가, ㅂ니다, 였).
+    pub surface: String,
+    /// The part-of-speech tag of the Sejong tag set used by mecab-ko-dic (VV, EF, NNG).
+    pub pos: String,
+}
+
+impl Morpheme {
+    /// Creates a morpheme from its surface form and part-of-speech tag.
+    pub fn new(surface: &str, pos: &str) -> Self {
+        Self {
+            surface: surface.to_string(),
+            pos: pos.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Morpheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.surface, self.pos)
+    }
+}
+
+/// One lexicon entry: the context ids and cost of a surface form, and what it analyzes into.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    left_id: usize,
+    right_id: usize,
+    cost: i64,
+    morphemes: Vec<Morpheme>,
+}
+
+/// A morpheme lexicon with connection costs, for splitting text into morphemes.
+///
+/// Entries are read from CSV in the mecab-ko-dic format: surface, left context id, right context
+/// id, cost, part of speech, and then semantic class, final consonant, reading, type, first and
+/// last part of speech and expression. Only the first five columns are required. An expression
+/// such as `하/VV/*+였/EP/*` makes an entry like 했 analyze into several morphemes. Connection
+/// costs between a right and the next left context id are read from a `matrix.def` file; pairs
+/// it does not list cost nothing.
+///
+/// `analyze` builds a lattice of every entry found in each eojeol, with a syllable's final
+/// consonant able to begin the next morpheme (갑니다 → 가 + ㅂ니다), and picks the path of least
+/// cost with the Viterbi algorithm. Text no entry covers is tagged by its characters: SL for
+/// Latin letters, SN for digits, SH for hanja, SF for sentence-final punctuation, SY for other
+/// symbols and UNKNOWN for a run of Hangul.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{Lexicon, Morpheme};
+///
+/// let mut lexicon = Lexicon::parse(
+///     "학교,1,1,100,NNG,*,F,학교,*,*,*,*\n\
+///      에,2,2,50,JKB,*,F,에,*,*,*,*\n\
+///      가,3,3,100,VV,*,F,가,*,*,*,*\n\
+///      가,2,2,100,JKS,*,F,가,*,*,*,*\n\
+///      ㅂ니다,4,4,50,EF,*,F,ㅂ니다,*,*,*,*\n\
+///      했,3,5,100,VV+EP,*,T,했,Inflect,VV,EP,하/VV/*+였/EP/*\n\
+///      어,4,4,50,EF,*,F,어,*,*,*,*\n",
+/// )
+/// .unwrap();
+/// lexicon.set_connections("6 6\n2 3 0\n1 2 0\n3 4 0\n5 4 0\n2 4 5000\n").unwrap();
+///
+/// let tags: Vec<String> = lexicon.analyze("학교에 갑니다").iter().map(|m| m.to_string()).collect();
+/// assert_eq!(tags, vec!["학교/NNG", "에/JKB", "가/VV", "ㅂ니다/EF"]);
+/// assert_eq!(
+///     lexicon.analyze("했어"),
+///     vec![Morpheme::new("하", "VV"), Morpheme::new("였", "EP"), Morpheme::new("어", "EF")]
+/// );
+/// assert_eq!(lexicon.analyze("API"), vec![Morpheme::new("API", "SL")]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Lexicon {
+    entries: HashMap<String, Vec<Entry>>,
+    /// The length in characters of the longest surface form.
+    longest: usize,
+    /// Connection costs, indexed by right id + `right_size` * left id.
+    connections: Vec<i16>,
+    right_size: usize,
+    left_size: usize,
+}
+
+impl Lexicon {
+    /// Creates an empty lexicon.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads a lexicon from CSV lines in the mecab-ko-dic format.
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - The lexicon, or a message naming the first line that is not
+    ///   a valid entry.
+    pub fn parse(csv: &str) -> Result<Self, String> {
+        let mut lexicon = Self::new();
+        lexicon.add_entries(csv)?;
+        Ok(lexicon)
+    }
+
+    /// Reads a lexicon from a CSV file in the format of `parse`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))
+    }
+
+    /// Adds the entries of more CSV lines, as mecab-ko-dic keeps each part of speech in its own
+    /// file (NNG.csv, VV.csv, ...).
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - A message naming the first line that is not a valid entry;
+    ///   the entries before it are kept.
+    pub fn add_entries(&mut self, csv: &str) -> Result<(), String> {
+        for (number, line) in csv.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (surface, entry) = parse_entry(line)
+                .ok_or_else(|| format!("line {}: invalid entry \"{}\"", number + 1, line))?;
+            self.longest = self.longest.max(surface.chars().count());
+            self.entries.entry(surface).or_default().push(entry);
+        }
+        Ok(())
+    }
+
+    /// Reads connection costs in the format of mecab's `matrix.def`: a line with the number of
+    /// right and left context ids, then lines of a right id, the left id that follows it and
+    /// the cost of the pair.
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - A message naming the first line that is not valid.
+    pub fn set_connections(&mut self, matrix: &str) -> Result<(), String> {
+        let mut lines = matrix
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let invalid =
+            |number: usize, line: &str| format!("line {}: invalid entry \"{}\"", number + 1, line);
+
+        let (number, header) = lines.next().ok_or("missing matrix size")?;
+        let sizes: Vec<usize> = header
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid(number, header))?;
+        let [right_size, left_size] = sizes[..] else {
+            return Err(invalid(number, header));
+        };
+        let mut connections = vec![0; right_size * left_size];
+
+        for (number, line) in lines {
+            let fields: Vec<i64> = line
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map_err(|_| invalid(number, line))?;
+            let [right_id, left_id, cost] = fields[..] else {
+                return Err(invalid(number, line));
+            };
+            let (Ok(right_id), Ok(left_id), Ok(cost)) = (
+                usize::try_from(right_id),
+                usize::try_from(left_id),
+                i16::try_from(cost),
+            ) else {
+                return Err(invalid(number, line));
+            };
+            if right_id >= right_size || left_id >= left_size {
+                return Err(invalid(number, line));
+            }
+            connections[right_id + right_size * left_id] = cost;
+        }
+
+        self.connections = connections;
+        self.right_size = right_size;
+        self.left_size = left_size;
+        Ok(())
+    }
+
+    /// Splits text into morphemes with their part-of-speech tags, eojeol by eojeol.
+    ///
+    /// # Arguments
+    /// * `text` - The text to analyze.
+    ///
+    /// # Returns
+    /// * `Vec<Morpheme>` - The morphemes of the path of least cost through each eojeol.
+    pub fn analyze(&self, text: &str) -> Vec<Morpheme> {
+        text.split_whitespace()
+            .flat_map(|eojeol| self.analyze_eojeol(eojeol))
+            .collect()
+    }
+
+    fn analyze_eojeol(&self, eojeol: &str) -> Vec<Morpheme> {
+        let chars: Vec<char> = eojeol.chars().collect();
+        let nodes = self.lattice(&chars);
+        let last = 2 * chars.len();
+
+        let mut ending_at: Vec<Vec<usize>> = vec![Vec::new(); last + 1];
+        let mut best: Vec<Option<(i64, Option<usize>)>> = vec![None; nodes.len()];
+        for (index, node) in nodes.iter().enumerate() {
+            let previous = if node.start == 0 {
+                Some((self.connection(0, node.entry.left_id), None))
+            } else {
+                ending_at[node.start]
+                    .iter()
+                    .filter_map(|&before| {
+                        let (cost, _) = best[before]?;
+                        let connection =
+                            self.connection(nodes[before].entry.right_id, node.entry.left_id);
+                        Some((cost + connection, Some(before)))
+                    })
+                    .min_by_key(|&(cost, _)| cost)
+            };
+            if let Some((cost, before)) = previous {
+                best[index] = Some((cost + node.entry.cost, before));
+                ending_at[node.end].push(index);
+            }
+        }
+
+        let mut current = ending_at[last]
+            .iter()
+            .filter_map(|&index| {
+                let (cost, _) = best[index]?;
+                Some((
+                    cost + self.connection(nodes[index].entry.right_id, 0),
+                    index,
+                ))
+            })
+            .min_by_key(|&(cost, _)| cost)
+            .map(|(_, index)| index);
+
+        let mut path = Vec::new();
+        while let Some(index) = current {
+            path.push(index);
+            current = best[index].and_then(|(_, before)| before);
+        }
+
+        let mut morphemes: Vec<Morpheme> = Vec::new();
+        for &index in path.iter().rev() {
+            for morpheme in &nodes[index].entry.morphemes {
+                match morphemes.last_mut() {
+                    // unknown Hangul is placed a syllable at a time, but read as one word (갑자기)
+                    Some(previous) if previous.pos == "UNKNOWN" && morpheme.pos == "UNKNOWN" => {
+                        previous.surface.push_str(&morpheme.surface)
+                    }
+                    _ => morphemes.push(morpheme.clone()),
+                }
+            }
+        }
+        morphemes
+    }
+
+    /// Every entry found in the eojeol, and a node for unknown text at each syllable.
+    ///
+    /// Position 2i is the boundary before character i, and 2i + 1 the point inside syllable i
+    /// between its vowel and its final consonant.
+    fn lattice(&self, chars: &[char]) -> Vec<Node> {
+        let has_final = |index: usize| {
+            chars
+                .get(index)
+                .and_then(|&c| split_one_letter(c))
+                .is_some_and(|(_, _, jong)| jong.is_some())
+        };
+        let last = 2 * chars.len();
+        let mut nodes = Vec::new();
+
+        for start in 0..last {
+            if start % 2 == 1 && !has_final(start / 2) {
+                continue;
+            }
+            for end in start + 1..=last {
+                if end / 2 - start / 2 > self.longest {
+                    break;
+                }
+                if end % 2 == 1 && !has_final(end / 2) {
+                    continue;
+                }
+                let Some(surface) = lattice_surface(chars, start, end) else {
+                    continue;
+                };
+                for entry in self.entries.get(&surface).into_iter().flatten() {
+                    nodes.push(Node {
+                        start,
+                        end,
+                        entry: entry.clone(),
+                    });
+                }
+            }
+            if start % 2 == 0 {
+                nodes.push(unknown_node(chars, start / 2));
+            }
+        }
+
+        nodes.sort_by_key(|node| node.start);
+        nodes
+    }
+
+    fn connection(&self, right_id: usize, left_id: usize) -> i64 {
+        if right_id < self.right_size && left_id < self.left_size {
+            i64::from(self.connections[right_id + self.right_size * left_id])
+        } else {
+            0
+        }
+    }
+}
+
+/// An entry placed in the lattice between two positions.
+#[derive(Debug, Clone)]
+struct Node {
+    start: usize,
+    end: usize,
+    entry: Entry,
+}
+
+/// The text between two lattice positions, with a final consonant on its own as a jamo.
+fn lattice_surface(chars: &[char], start: usize, end: usize) -> Option<String> {
+    let (first, last) = (start / 2, end / 2);
+    let mut surface = String::new();
+    let mut index = first;
+
+    if start % 2 == 1 {
+        let (_, _, jong) = split_one_letter(chars[first])?;
+        surface.push(jong?);
+        index += 1;
+    }
+    if end % 2 == 1 && last < index {
+        return None;
+    }
+    surface.extend(&chars[index..last]);
+    if end % 2 == 1 {
+        let (cho, jung, _) = split_one_letter(chars[last])?;
+        surface.push(syllable(cho, jung, None));
+    }
+    Some(surface)
+}
+
+/// A node for text the lexicon may not know: one Hangul syllable, or a run of Latin letters,
+/// digits or hanja.
+fn unknown_node(chars: &[char], index: usize) -> Node {
+    let class = |c: char| match c {
+        _ if split_one_letter(c).is_some() => "UNKNOWN",
+        'a'..='z' | 'A'..='Z' => "SL",
+        '0'..='9' => "SN",
+        '\u{4E00}'..='\u{9FFF}' => "SH",
+        '.' | '?' | '!' => "SF",
+        _ => "SY",
+    };
+    let pos = class(chars[index]);
+    let length = match pos {
+        "SL" | "SN" | "SH" => chars[index..]
+            .iter()
+            .take_while(|&&c| class(c) == pos)
+            .count(),
+        _ => 1,
+    };
+    let surface: String = chars[index..index + length].iter().collect();
+
+    Node {
+        start: 2 * index,
+        end: 2 * (index + length),
+        entry: Entry {
+            left_id: 0,
+            right_id: 0,
+            cost: UNKNOWN_COST,
+            morphemes: vec![Morpheme::new(&surface, pos)],
+        },
+    }
+}
+
+/// Reads one CSV line of a mecab-ko-dic lexicon.
+fn parse_entry(line: &str) -> Option<(String, Entry)> {
+    let fields = csv_fields(line);
+    if fields.len() < 5 || fields[0].is_empty() || fields[4].is_empty() {
+        return None;
+    }
+    let surface = fields[0].clone();
+    let pos = fields[4].as_str();
+
+    // 하/VV/*+였/EP/*
+    let expression = fields
+        .get(11)
+        .filter(|expression| expression.as_str() != "*");
+    let morphemes = match expression {
+        Some(expression) => expression
+            .split('+')
+            .map(|part| {
+                let mut pieces = part.split('/');
+                let surface = pieces.next().filter(|surface| !surface.is_empty())?;
+                let pos = pieces.next().filter(|pos| !pos.is_empty())?;
+                Some(Morpheme::new(surface, pos))
+            })
+            .collect::<Option<Vec<_>>>()?,
+        None => vec![Morpheme::new(&surface, pos)],
+    };
+
+    Some((
+        surface,
+        Entry {
+            left_id: fields[1].parse().ok()?,
+            right_id: fields[2].parse().ok()?,
+            cost: fields[3].parse().ok()?,
+            morphemes,
+        },
+    ))
+}
+
+/// Splits a CSV line into fields, with double quotes around fields that hold commas.
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
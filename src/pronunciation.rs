@@ -0,0 +1,1003 @@
+use crate::compose_korean::{
+    make_one_letter, split_double_consonant, split_one_letter, tense_consonant,
+};
+use crate::enums::{SoundRule, WordOrigin};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A complete Hangul syllable broken into its jamo.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Syllable {
+    pub(crate) cho: char,
+    pub(crate) jung: char,
+    pub(crate) jong: Option<char>,
+}
+
+impl Syllable {
+    pub(crate) fn from_char(character: char) -> Option<Self> {
+        split_one_letter(character).map(|(cho, jung, jong)| Syllable { cho, jung, jong })
+    }
+
+    pub(crate) fn to_char(self) -> char {
+        let mut jamo = format!("{}{}", self.cho, self.jung);
+        jamo.extend(self.jong);
+        make_one_letter(jamo)
+    }
+}
+
+/// A run of text: either consecutive Hangul syllables or anything in between.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Segment {
+    Word(Vec<Syllable>),
+    Other(String),
+}
+
+/// Splits text into runs of Hangul syllables and runs of everything else.
+pub(crate) fn segments(text: &str) -> Vec<Segment> {
+    let mut result = Vec::new();
+
+    for character in text.chars() {
+        match (Syllable::from_char(character), result.last_mut()) {
+            (Some(syllable), Some(Segment::Word(word))) => word.push(syllable),
+            (Some(syllable), _) => result.push(Segment::Word(vec![syllable])),
+            (None, Some(Segment::Other(other))) => other.push(character),
+            (None, _) => result.push(Segment::Other(character.to_string())),
+        }
+    }
+
+    result
+}
+
+/// Converts Korean text into its standard pronunciation (표준 발음법), written in Hangul.
+///
+/// Inside a word this applies linking (연음), final neutralization and compound final
+/// simplification, ㅎ aspiration, palatalization, tensification, nasalization and
+/// liquidization, except that ㄴ before the suffixes 란, 량 and 력 makes them ㄴ (의견란 →
+/// 의견난). Across a space, a final links to the next word when that word starts with
+/// ㅏ ㅓ ㅗ ㅜ ㅟ after being neutralized (옷 안 → 오 단). Characters that are not Hangul
+/// syllables are kept as they are. Tensing that depends on a word being Sino-Korean (갈등 →
+/// 갈뜽, 제26항) is left to `PronunciationDictionary::standard`.
+///
+/// A '+' between Hangul syllables marks where the morphemes of a compound meet and is left
+/// out of the result. There ㄴ is added before 이 야 여 요 유 (솜+이불 → 솜니불) and a final
+/// links to any other vowel only after being neutralized (겉+옷 → 거돋), as across a space.
+///
+/// # Arguments
+/// * `text` - A `&str` to pronounce.
+///
+/// # Returns
+/// * `String` - The pronunciation in Hangul.
+///
+/// # Examples
+/// ```
+/// use rustkorean::pronounce;
+///
+/// assert_eq!(pronounce("읽는다"), "잉는다");
+/// assert_eq!(pronounce("신라"), "실라");
+/// assert_eq!(pronounce("굳이"), "구지");
+/// assert_eq!(pronounce("좋고"), "조코");
+/// assert_eq!(pronounce("옷 안"), "오 단");
+/// assert_eq!(pronounce("꽃+잎"), "꼰닙");
+/// ```
+pub fn pronounce(text: &str) -> String {
+    pronounce_with_trace(text).0
+}
+
+/// One sound change made while pronouncing, between the final of a syllable and the initial
+/// of the syllable after it.
+///
+/// Its `Display` form names the rule and shows the jamo before and after the change, such as
+/// "제18항 비음화: ㄱ+ㄴ → ㅇ+ㄴ".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundChange {
+    /// The rule that was applied.
+    pub rule: SoundRule,
+    /// The index, in characters of the text, of the syllable whose final is involved.
+    pub position: usize,
+    /// The final and the following initial before the change. The initial is `None` at the
+    /// end of a word.
+    pub before: (Option<char>, Option<char>),
+    /// The final and the following initial after the change.
+    pub after: (Option<char>, Option<char>),
+}
+
+impl SoundChange {
+    /// Returns the article of the standard pronunciation (표준 발음법) the change follows.
+    pub fn article(&self) -> u8 {
+        match self.rule {
+            SoundRule::Neutralization => 9,
+            SoundRule::CompoundFinal => match self.before.0 {
+                Some('ㄺ' | 'ㄻ' | 'ㄿ') => 11,
+                _ => 10,
+            },
+            SoundRule::HieutFinal | SoundRule::Aspiration => 12,
+            SoundRule::Linking => match self.before.0.and_then(split_double_consonant) {
+                Some(_) => 14,
+                None => 13,
+            },
+            SoundRule::WordLinking => 15,
+            SoundRule::NieunInsertion => 29,
+            SoundRule::Palatalization => 17,
+            SoundRule::Nasalization => 18,
+            SoundRule::RieulNasalization => 19,
+            SoundRule::Liquidization => 20,
+            SoundRule::Tensification => 23,
+        }
+    }
+
+    /// Returns the Korean name of the rule.
+    pub fn name(&self) -> &'static str {
+        match self.rule {
+            SoundRule::Neutralization => "받침 대표음",
+            SoundRule::CompoundFinal => "겹받침 발음",
+            SoundRule::HieutFinal => "ㅎ 받침 발음",
+            SoundRule::Aspiration => "거센소리되기",
+            SoundRule::Linking | SoundRule::WordLinking => "연음",
+            SoundRule::Palatalization => "구개음화",
+            SoundRule::Nasalization => "비음화",
+            SoundRule::RieulNasalization => "ㄹ의 비음화",
+            SoundRule::Liquidization => "유음화",
+            SoundRule::Tensification => "된소리되기",
+            SoundRule::NieunInsertion => "ㄴ 첨가",
+        }
+    }
+}
+
+impl fmt::Display for SoundChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let jamo = |(jong, cho): (Option<char>, Option<char>)| {
+            jong.into_iter()
+                .chain(cho)
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join("+")
+        };
+        write!(
+            f,
+            "제{}항 {}: {} → {}",
+            self.article(),
+            self.name(),
+            jamo(self.before),
+            jamo(self.after)
+        )
+    }
+}
+
+/// Converts Korean text into its standard pronunciation and lists every sound change made,
+/// in the order they were applied.
+///
+/// # Arguments
+/// * `text` - A `&str` to pronounce.
+///
+/// # Returns
+/// * `(String, Vec<SoundChange>)` - The pronunciation in Hangul and the changes that produced it.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{pronounce_with_trace, SoundRule};
+///
+/// let (pronunciation, changes) = pronounce_with_trace("국물");
+/// assert_eq!(pronunciation, "궁물");
+/// assert_eq!(changes[0].rule, SoundRule::Nasalization);
+/// assert_eq!(changes[0].to_string(), "제18항 비음화: ㄱ+ㅁ → ㅇ+ㅁ");
+///
+/// let (_, changes) = pronounce_with_trace("굳이");
+/// assert_eq!(changes[0].to_string(), "제17항 구개음화: ㄷ+ㅇ → ㅈ");
+/// ```
+pub fn pronounce_with_trace(text: &str) -> (String, Vec<SoundChange>) {
+    pronounce_words(text, &mut |_| None)
+}
+
+/// Pronounces `text`, taking the pronunciation of a word from `lookup` when it returns one
+/// instead of applying the rules inside that word.
+fn pronounce_words(
+    text: &str,
+    lookup: &mut dyn FnMut(&str) -> Option<Vec<Syllable>>,
+) -> (String, Vec<SoundChange>) {
+    let (mut segments, boundaries): (Vec<Segment>, Vec<Vec<usize>>) =
+        compounds(text).into_iter().unzip();
+    // lengths in the original text, which a looked up pronunciation may not keep
+    let lengths: Vec<usize> = segments
+        .iter()
+        .zip(&boundaries)
+        .map(|(segment, boundaries)| match segment {
+            Segment::Word(word) => word.len() + boundaries.len(),
+            Segment::Other(other) => other.chars().count(),
+        })
+        .collect();
+    let mut changes = Vec::new();
+    let mut position = 0;
+
+    for ((segment, boundaries), length) in segments.iter_mut().zip(&boundaries).zip(&lengths) {
+        if let Segment::Word(word) = segment {
+            let spelled: String = word.iter().map(|syllable| syllable.to_char()).collect();
+            match lookup(&spelled) {
+                Some(pronunciation) => *word = pronunciation,
+                None => {
+                    let word_changes = apply_sound_changes_traced(
+                        word,
+                        SoundRules {
+                            tensification: true,
+                        },
+                        boundaries,
+                    );
+                    changes.extend(word_changes.into_iter().map(|mut change| {
+                        // skip the '+' marks before the syllable
+                        let marks = boundaries
+                            .iter()
+                            .filter(|&&boundary| boundary <= change.position)
+                            .count();
+                        change.position += position + marks;
+                        change
+                    }));
+                }
+            }
+        }
+        position += length;
+    }
+
+    // linking across a space (제15항)
+    let mut position = 0;
+    for index in 0..segments.len() {
+        let (before, after) = segments.split_at_mut(index);
+        if let ([.., Segment::Word(previous), Segment::Other(gap)], [Segment::Word(next), ..]) =
+            (before, after)
+        {
+            if gap.chars().all(|character| character == ' ') {
+                let last = position - lengths[index - 1] - 1;
+                changes.extend(link_words(previous, next, last));
+            }
+        }
+        position += lengths[index];
+    }
+
+    let pronunciation = segments
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Word(word) => word.into_iter().map(Syllable::to_char).collect(),
+            Segment::Other(other) => other,
+        })
+        .collect();
+
+    (pronunciation, changes)
+}
+
+/// Pronunciations of words that the rules get wrong or that have more than one accepted
+/// form, consulted before the rules are applied.
+///
+/// Entries can be added in code with `insert` or read from text where each line holds a word
+/// followed by its pronunciations, separated by whitespace. The first pronunciation of a word
+/// is the preferred one. A line starting with '#' is a comment.
+///
+/// # Examples
+/// ```
+/// use rustkorean::PronunciationDictionary;
+///
+/// let mut dictionary = PronunciationDictionary::new();
+/// dictionary.insert("맛있다", "마딛따");
+/// dictionary.insert("맛있다", "마싣따");
+/// assert_eq!(dictionary.pronounce("정말 맛있다"), "정말 마딛따");
+/// assert_eq!(
+///     dictionary.pronounce_variants("맛있다"),
+///     vec!["마딛따".to_string(), "마싣따".to_string()]
+/// );
+///
+/// let dictionary = PronunciationDictionary::parse("# word pronunciations\n효과 효과 효꽈\n").unwrap();
+/// assert_eq!(dictionary.get("효과"), Some(&["효과".to_string(), "효꽈".to_string()][..]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PronunciationDictionary {
+    entries: HashMap<String, Vec<String>>,
+}
+
+impl PronunciationDictionary {
+    /// Creates an empty dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a dictionary with words whose standard pronunciation the rules do not give,
+    /// such as 맛있다 [마딛따/마싣따] and 효과 [효과/효꽈].
+    pub fn standard() -> Self {
+        let mut dictionary = Self::new();
+        for &(word, pronunciations) in STANDARD_PRONUNCIATIONS.iter() {
+            for pronunciation in pronunciations {
+                dictionary.insert(word, pronunciation);
+            }
+        }
+        dictionary
+    }
+
+    /// Adds `pronunciation` as an accepted pronunciation of `word`, after the ones it already has.
+    ///
+    /// # Returns
+    /// * `bool` - `false` if the word or the pronunciation is not made of Hangul syllables only,
+    ///   in which case nothing is added.
+    pub fn insert(&mut self, word: &str, pronunciation: &str) -> bool {
+        if !is_hangul_word(word) || !is_hangul_word(pronunciation) {
+            return false;
+        }
+        let pronunciations = self.entries.entry(word.to_string()).or_default();
+        if !pronunciations.iter().any(|known| known == pronunciation) {
+            pronunciations.push(pronunciation.to_string());
+        }
+        true
+    }
+
+    /// Returns the accepted pronunciations of `word`, the preferred one first.
+    pub fn get(&self, word: &str) -> Option<&[String]> {
+        self.entries.get(word).map(Vec::as_slice)
+    }
+
+    /// Reads a dictionary from text with one word and its pronunciations per line.
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - The dictionary, or a message naming the first line that is
+    ///   not a word followed by at least one pronunciation in Hangul.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut dictionary = Self::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let word = fields.next().unwrap_or_default();
+            let pronunciations: Vec<&str> = fields.collect();
+            if pronunciations.is_empty()
+                || !pronunciations
+                    .iter()
+                    .all(|pronunciation| dictionary.insert(word, pronunciation))
+            {
+                return Err(format!("line {}: invalid entry \"{}\"", number + 1, line));
+            }
+        }
+
+        Ok(dictionary)
+    }
+
+    /// Reads a dictionary from a file in the format of `parse`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))
+    }
+
+    /// Converts text into its standard pronunciation like `pronounce`, using the preferred
+    /// pronunciation of every word found in the dictionary.
+    pub fn pronounce(&self, text: &str) -> String {
+        pronounce_words(text, &mut |word| self.lookup(word, 0)).0
+    }
+
+    /// Converts text into every accepted pronunciation, the preferred one first, trying each
+    /// pronunciation of every word found in the dictionary.
+    pub fn pronounce_variants(&self, text: &str) -> Vec<String> {
+        let counts: Vec<usize> = segments(text)
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Word(word) => {
+                    let spelled: String = word.iter().map(|syllable| syllable.to_char()).collect();
+                    self.get(&spelled).map(<[String]>::len)
+                }
+                Segment::Other(_) => None,
+            })
+            .collect();
+
+        let mut result = Vec::new();
+        let mut choice = vec![0; counts.len()];
+        loop {
+            let mut word = 0;
+            let (pronunciation, _) = pronounce_words(text, &mut |spelled| {
+                let found = self.lookup(spelled, choice.get(word).copied().unwrap_or(0));
+                word += usize::from(found.is_some());
+                found
+            });
+            if !result.contains(&pronunciation) {
+                result.push(pronunciation);
+            }
+
+            // the next combination of choices, the last word changing fastest
+            let Some(index) = (0..counts.len())
+                .rev()
+                .find(|&index| choice[index] + 1 < counts[index])
+            else {
+                return result;
+            };
+            choice[index] += 1;
+            choice[index + 1..].fill(0);
+        }
+    }
+
+    pub(crate) fn lookup(&self, word: &str, variant: usize) -> Option<Vec<Syllable>> {
+        let pronunciation = self.get(word)?.get(variant)?;
+        pronunciation.chars().map(Syllable::from_char).collect()
+    }
+}
+
+/// Words the standard pronunciation lists apart from the rules, the preferred form first.
+const STANDARD_PRONUNCIATIONS: [(&str, &[&str]); 28] = [
+    ("맛있다", &["마딛따", "마싣따"]),
+    ("멋있다", &["머딛따", "머싣따"]),
+    ("효과", &["효과", "효꽈"]),
+    ("교과서", &["교과서", "교꽈서"]),
+    ("관건", &["관건", "관껀"]),
+    ("불법", &["불법", "불뻡"]),
+    ("김밥", &["김밥", "김빱"]),
+    ("안간힘", &["안간힘", "안깐힘"]),
+    ("반창고", &["반창꼬"]),
+    ("인기", &["인끼"]),
+    ("서울역", &["서울력"]),
+    // tensed in the words themselves, beside the ㄴ of 제20항 다만
+    ("결단력", &["결딴녁"]),
+    ("공권력", &["공꿘녁"]),
+    // ㄷ ㅅ ㅈ after the ㄹ of a Sino-Korean word (제26항)
+    ("갈등", &["갈뜽"]),
+    ("발동", &["발똥"]),
+    ("절도", &["절또"]),
+    ("말살", &["말쌀"]),
+    ("불소", &["불쏘"]),
+    ("일시", &["일씨"]),
+    ("갈증", &["갈쯩"]),
+    ("물질", &["물찔"]),
+    ("발전", &["발쩐"]),
+    ("몰상식", &["몰쌍식"]),
+    ("불세출", &["불쎄출"]),
+    ("실수", &["실쑤"]),
+    ("열정", &["열쩡"]),
+    ("일정", &["일쩡"]),
+    ("출석", &["출썩"]),
+];
+
+fn is_hangul_word(word: &str) -> bool {
+    !word.is_empty()
+        && word
+            .chars()
+            .all(|character| Syllable::from_char(character).is_some())
+}
+
+/// Splits text like `segments`, joining Hangul words written with '+' between morphemes
+/// (솜+이불) into one word. Each word comes with the indices of the syllables that start a
+/// new morpheme.
+pub(crate) fn compounds(text: &str) -> Vec<(Segment, Vec<usize>)> {
+    let mut result: Vec<(Segment, Vec<usize>)> = Vec::new();
+    let mut pending_mark = false;
+
+    for segment in segments(text) {
+        match (segment, result.last_mut()) {
+            (Segment::Other(other), Some((Segment::Word(_), _))) if other == "+" => {
+                pending_mark = true;
+                continue;
+            }
+            (Segment::Word(word), Some((Segment::Word(previous), boundaries))) if pending_mark => {
+                boundaries.push(previous.len());
+                previous.extend(word);
+            }
+            (segment, _) => {
+                if pending_mark {
+                    result.push((Segment::Other("+".to_string()), Vec::new()));
+                }
+                result.push((segment, Vec::new()));
+            }
+        }
+        pending_mark = false;
+    }
+    if pending_mark {
+        result.push((Segment::Other("+".to_string()), Vec::new()));
+    }
+
+    result
+}
+
+/// Sino-Korean compounds written with 사이시옷 although both parts are Sino-Korean (제30항 3).
+const SINO_KOREAN_SAISIOT: [&str; 6] = ["고+간", "세+방", "수+자", "차+간", "퇴+간", "회+수"];
+
+/// Checks whether a compound should be written with 사이시옷 (한글 맞춤법 제30항).
+///
+/// 사이시옷 is written when the first part ends in a vowel, at least one part is a native
+/// Korean word (or the compound is one of the six Sino-Korean exceptions such as 곳간 and
+/// 횟수), no part is a loanword, the second part does not already start with a tense or
+/// aspirated consonant, and the compound is pronounced with one of the 사잇소리 effects:
+/// the initial of the second part tensed (해+살 [해쌀]), ㄴ added before ㄴ or ㅁ
+/// (이+몸 [인몸]), or ㄴㄴ added before a vowel (나무+잎 [나문닙]). Whether a compound has one
+/// of these effects depends on the word, so its pronunciation has to be given.
+///
+/// # Arguments
+/// * `compound` - The parts of the compound without 사이시옷, joined by '+' (나무+잎).
+/// * `pronunciation` - How the compound is pronounced, in Hangul (나문닙).
+/// * `first` - The `WordOrigin` of the first part.
+/// * `second` - The `WordOrigin` of the second part.
+///
+/// # Returns
+/// * `bool` - `true` if the compound should be written with 사이시옷 (나뭇잎).
+///
+/// # Examples
+/// ```
+/// use rustkorean::{needs_saisiot, WordOrigin};
+///
+/// assert!(needs_saisiot("나무+잎", "나문닙", WordOrigin::Native, WordOrigin::Native));
+/// assert!(needs_saisiot("해+살", "해쌀", WordOrigin::Native, WordOrigin::Native));
+/// assert!(needs_saisiot("세+방", "세빵", WordOrigin::SinoKorean, WordOrigin::SinoKorean));
+/// assert!(!needs_saisiot("초+점", "초쩜", WordOrigin::SinoKorean, WordOrigin::SinoKorean));
+/// assert!(!needs_saisiot("위+쪽", "위쪽", WordOrigin::Native, WordOrigin::Native));
+/// assert!(!needs_saisiot("머리+말", "머리말", WordOrigin::Native, WordOrigin::Native));
+/// ```
+pub fn needs_saisiot(
+    compound: &str,
+    pronunciation: &str,
+    first: WordOrigin,
+    second: WordOrigin,
+) -> bool {
+    let hangul = |text: &str| {
+        text.chars()
+            .map(Syllable::from_char)
+            .collect::<Option<Vec<_>>>()
+    };
+    let Some((first_part, second_part)) = compound.split_once('+') else {
+        return false;
+    };
+    let (Some(first_part), Some(second_part), Some(sound)) = (
+        hangul(first_part),
+        hangul(second_part),
+        hangul(pronunciation),
+    ) else {
+        return false;
+    };
+    let (Some(last), Some(next)) = (first_part.last(), second_part.first()) else {
+        return false;
+    };
+    if last.jong.is_some()
+        || sound.len() != first_part.len() + second_part.len()
+        || matches!(
+            next.cho,
+            'ㄲ' | 'ㄸ' | 'ㅃ' | 'ㅆ' | 'ㅉ' | 'ㅊ' | 'ㅋ' | 'ㅌ' | 'ㅍ'
+        )
+    {
+        return false;
+    }
+
+    let origins_allow = match (first, second) {
+        (WordOrigin::Loanword, _) | (_, WordOrigin::Loanword) => false,
+        (WordOrigin::SinoKorean, WordOrigin::SinoKorean) => SINO_KOREAN_SAISIOT.contains(&compound),
+        _ => true,
+    };
+
+    let (sound_last, sound_next) = (sound[first_part.len() - 1], sound[first_part.len()]);
+    let added_nieun = sound_last.jong == Some('ㄴ');
+    let sound_effect = match next.cho {
+        'ㄱ' | 'ㄷ' | 'ㅂ' | 'ㅅ' | 'ㅈ' => tense_consonant(next.cho) == Some(sound_next.cho),
+        'ㄴ' | 'ㅁ' => added_nieun,
+        'ㅇ' => added_nieun && sound_next.cho == 'ㄴ',
+        _ => false,
+    };
+
+    origins_allow && sound_effect
+}
+
+/// Moves the already neutralized final of `previous` to the empty initial of `next`.
+/// `position` is the index of the last syllable of `previous` in the text.
+fn link_words(
+    previous: &mut [Syllable],
+    next: &mut [Syllable],
+    position: usize,
+) -> Option<SoundChange> {
+    let (before, after) = (previous.last_mut()?, next.first_mut()?);
+    let jong = before.jong?;
+
+    if jong == 'ㅇ' || after.cho != 'ㅇ' || !matches!(after.jung, 'ㅏ' | 'ㅓ' | 'ㅗ' | 'ㅜ' | 'ㅟ')
+    {
+        return None;
+    }
+
+    before.jong = None;
+    after.cho = jong;
+    Some(SoundChange {
+        rule: SoundRule::WordLinking,
+        position,
+        before: (Some(jong), Some('ㅇ')),
+        after: (None, Some(jong)),
+    })
+}
+
+/// Which optional sound changes to apply on top of the ones every pronunciation needs.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SoundRules {
+    /// Tense the initial after an obstruent final (국밥 → 국빱).
+    /// Revised Romanization does not write tensification, so it leaves this off.
+    pub(crate) tensification: bool,
+}
+
+/// Applies the standard sound changes (표준 발음법) inside one word whose morphemes start at
+/// the syllables in `boundaries`, as given by `compounds`: ㅎ aspiration, palatalization,
+/// linking (연음), final neutralization, tensification, nasalization and liquidization. When
+/// `dictionary` lists the word, its preferred pronunciation is taken instead; without
+/// tensification in `rules`, the tense initials of that pronunciation are written as spelled.
+pub(crate) fn pronounce_word(
+    word: &mut Vec<Syllable>,
+    rules: SoundRules,
+    boundaries: &[usize],
+    dictionary: Option<&PronunciationDictionary>,
+) {
+    let spelled: String = word.iter().map(|syllable| syllable.to_char()).collect();
+    let Some(mut pronounced) = dictionary.and_then(|dictionary| dictionary.lookup(&spelled, 0))
+    else {
+        apply_sound_changes_traced(word, rules, boundaries);
+        return;
+    };
+
+    if !rules.tensification && pronounced.len() == word.len() {
+        for (sound, written) in pronounced.iter_mut().zip(word.iter()) {
+            if tense_consonant(written.cho) == Some(sound.cho) {
+                sound.cho = written.cho;
+            }
+        }
+    }
+    *word = pronounced;
+}
+
+/// Applies the sound changes of `pronounce_word` and returns them, with positions
+/// counted from the start of the word. `boundaries` are the indices of the syllables that
+/// start a new morpheme of a compound.
+fn apply_sound_changes_traced(
+    word: &mut [Syllable],
+    rules: SoundRules,
+    boundaries: &[usize],
+) -> Vec<SoundChange> {
+    let mut trace = Trace::default();
+
+    for index in 1..word.len() {
+        let (before, after) = word.split_at_mut(index);
+        trace.position = index - 1;
+        let boundary = boundaries.contains(&index);
+        // 란, 량 and 력 after a word of two or more syllables are suffixes (의견+란, 생산+량)
+        let suffix = index >= 2 && matches!(after[0].to_char(), '란' | '량' | '력');
+        change_boundary(
+            &mut before[index - 1],
+            &mut after[0],
+            rules,
+            boundary,
+            suffix,
+            &mut trace,
+        );
+    }
+
+    if let Some(last) = word.last_mut() {
+        if let Some(jong) = last.jong {
+            let final_sound = resolve_final(last, jong, None);
+            if final_sound != jong {
+                last.jong = Some(final_sound);
+                trace.changes.push(SoundChange {
+                    rule: neutralization_rule(jong),
+                    position: word.len() - 1,
+                    before: (Some(jong), None),
+                    after: (Some(final_sound), None),
+                });
+            }
+        }
+    }
+
+    trace.changes
+}
+
+/// Collects the sound changes made at one syllable boundary after another.
+#[derive(Default)]
+struct Trace {
+    position: usize,
+    changes: Vec<SoundChange>,
+}
+
+impl Trace {
+    /// Records `rule` if it changed the boundary from `from`.
+    fn record(
+        &mut self,
+        rule: SoundRule,
+        from: (Option<char>, char),
+        before: &Syllable,
+        after: &Syllable,
+    ) {
+        let to = (before.jong, after.cho);
+        if to != from {
+            self.changes.push(SoundChange {
+                rule,
+                position: self.position,
+                before: (from.0, Some(from.1)),
+                after: (to.0, Some(to.1)),
+            });
+        }
+    }
+}
+
+fn neutralization_rule(jong: char) -> SoundRule {
+    match split_double_consonant(jong) {
+        Some(_) => SoundRule::CompoundFinal,
+        None => SoundRule::Neutralization,
+    }
+}
+
+/// Nouns ending in ㄺ, whose final stays ㄱ before ㄱ unlike verb and adjective stems (닭고기 → 닥꼬기).
+const RIEUL_GIYEOK_NOUNS: [char; 4] = ['닭', '흙', '칡', '삵'];
+
+/// Reduces a final consonant to the one of the seven sounds a final can have (받침 대표음).
+///
+/// ㅋ ㄲ become ㄱ, ㅅ ㅆ ㅈ ㅊ ㅌ ㅎ become ㄷ and ㅍ becomes ㅂ (제9항). Compound finals, such
+/// as the ones `create_double_consonant` makes, keep their first consonant, except ㄺ ㄻ ㄿ
+/// which keep the second (제10항, 제11항). Use `final_sound` for the exceptions that depend on
+/// the word.
+///
+/// # Arguments
+/// * `jong` - A final consonant jamo.
+///
+/// # Returns
+/// * `char` - The representative sound, or `jong` itself if it is not a final consonant.
+///
+/// # Examples
+/// ```
+/// use rustkorean::representative_final;
+///
+/// assert_eq!(representative_final('ㅋ'), 'ㄱ');
+/// assert_eq!(representative_final('ㅊ'), 'ㄷ');
+/// assert_eq!(representative_final('ㄳ'), 'ㄱ');
+/// assert_eq!(representative_final('ㄻ'), 'ㅁ');
+/// ```
+pub fn representative_final(jong: char) -> char {
+    match jong {
+        'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' => 'ㄱ',
+        'ㄴ' | 'ㄵ' | 'ㄶ' => 'ㄴ',
+        'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => 'ㄷ',
+        'ㄹ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => 'ㄹ',
+        'ㅁ' | 'ㄻ' => 'ㅁ',
+        'ㅂ' | 'ㅍ' | 'ㄿ' | 'ㅄ' => 'ㅂ',
+        _ => jong,
+    }
+}
+
+/// Returns how the final of a syllable sounds before a consonant or at the end of a word.
+///
+/// Like `representative_final`, but with the documented exceptions for compound finals:
+/// the stem 밟- keeps ㅂ (밟다 → 밥따), so do 넓죽하다 and 넓둥글다 (넙쭈카다), and a verb or
+/// adjective stem ending in ㄺ keeps ㄹ before ㄱ (맑게 → 말께).
+///
+/// # Arguments
+/// * `syllable` - A Hangul syllable with a final consonant.
+/// * `next` - The syllable after it, or `None` at the end of a word.
+///
+/// # Returns
+/// * `Option<char>` - The sound of the final, or `None` if `syllable` has no final consonant.
+///
+/// # Examples
+/// ```
+/// use rustkorean::final_sound;
+///
+/// assert_eq!(final_sound('밟', Some('다')), Some('ㅂ'));
+/// assert_eq!(final_sound('넓', Some('다')), Some('ㄹ'));
+/// assert_eq!(final_sound('넓', Some('죽')), Some('ㅂ'));
+/// assert_eq!(final_sound('맑', Some('게')), Some('ㄹ'));
+/// assert_eq!(final_sound('맑', Some('다')), Some('ㄱ'));
+/// assert_eq!(final_sound('가', None), None);
+/// ```
+pub fn final_sound(syllable: char, next: Option<char>) -> Option<char> {
+    let before = Syllable::from_char(syllable)?;
+    let after = next.and_then(Syllable::from_char);
+    before
+        .jong
+        .map(|jong| resolve_final(&before, jong, after.as_ref()))
+}
+
+/// Resolves the final `jong` of `before` when it does not link to `after` (제9항 ~ 제11항).
+fn resolve_final(before: &Syllable, jong: char, after: Option<&Syllable>) -> char {
+    // the initial may already have changed at the boundary before (짓밟다 → 짇빱따)
+    let is = |cho: &[char], jung: char| cho.contains(&before.cho) && before.jung == jung;
+
+    match jong {
+        'ㄼ' if is(&['ㅂ', 'ㅃ'], 'ㅏ') => 'ㅂ',
+        'ㄼ' if is(&['ㄴ'], 'ㅓ')
+            && after.is_some_and(|after| {
+                matches!(
+                    (after.cho, after.jung, after.jong),
+                    ('ㅈ' | 'ㅉ', 'ㅜ', Some('ㄱ')) | ('ㄷ' | 'ㄸ', 'ㅜ', Some('ㅇ'))
+                )
+            }) =>
+        {
+            'ㅂ'
+        }
+        'ㄺ' if after.is_some_and(|after| after.cho == 'ㄱ')
+            && !RIEUL_GIYEOK_NOUNS.contains(&before.to_char()) =>
+        {
+            'ㄹ'
+        }
+        _ => representative_final(jong),
+    }
+}
+
+fn aspirate(cho: char) -> char {
+    match cho {
+        'ㄱ' => 'ㅋ',
+        'ㄷ' => 'ㅌ',
+        'ㅂ' => 'ㅍ',
+        'ㅈ' => 'ㅊ',
+        _ => cho,
+    }
+}
+
+fn tense(cho: char) -> char {
+    tense_consonant(cho).unwrap_or(cho)
+}
+
+/// Applies the sound changes between the final of `before` and the initial of `after`.
+/// `boundary` tells whether a new morpheme of a compound starts at `after`, and `suffix`
+/// whether `after` is a Sino-Korean suffix such as 란 in 의견란.
+fn change_boundary(
+    before: &mut Syllable,
+    after: &mut Syllable,
+    rules: SoundRules,
+    boundary: bool,
+    suffix: bool,
+    trace: &mut Trace,
+) {
+    let Some(jong) = before.jong else {
+        return;
+    };
+
+    if boundary && after.cho == 'ㅇ' {
+        let from = (before.jong, after.cho);
+        if matches!(after.jung, 'ㅣ' | 'ㅑ' | 'ㅕ' | 'ㅛ' | 'ㅠ') {
+            // ㄴ insertion (제29항): 솜+이불 → 솜니불, then the rules below go on with ㄴ
+            after.cho = 'ㄴ';
+            trace.record(SoundRule::NieunInsertion, from, before, after);
+        } else if jong != 'ㅇ' {
+            // the final is neutralized before it links to the next morpheme (제15항): 겉+옷 → 거돗
+            let final_sound = resolve_final(before, jong, None);
+            before.jong = Some(final_sound);
+            trace.record(neutralization_rule(jong), from, before, after);
+            let from = (before.jong, after.cho);
+            before.jong = None;
+            after.cho = final_sound;
+            trace.record(SoundRule::WordLinking, from, before, after);
+            return;
+        }
+    }
+
+    // ㅎ final (제12항): aspirates ㄱ ㄷ ㅈ, tenses ㅅ, becomes ㄴ before ㄴ and drops before a vowel
+    if matches!(jong, 'ㅎ' | 'ㄶ' | 'ㅀ') {
+        let from = (before.jong, after.cho);
+        let rest = match jong {
+            'ㄶ' => Some('ㄴ'),
+            'ㅀ' => Some('ㄹ'),
+            _ => None,
+        };
+        match after.cho {
+            'ㄱ' | 'ㄷ' | 'ㅈ' => {
+                before.jong = rest;
+                after.cho = aspirate(after.cho);
+                trace.record(SoundRule::Aspiration, from, before, after);
+                return;
+            }
+            'ㅅ' => {
+                before.jong = rest;
+                if rules.tensification {
+                    after.cho = 'ㅆ';
+                }
+                trace.record(SoundRule::HieutFinal, from, before, after);
+                return;
+            }
+            'ㄴ' => before.jong = rest.or(Some('ㄴ')),
+            'ㅇ' => before.jong = rest,
+            _ => {}
+        }
+        trace.record(SoundRule::HieutFinal, from, before, after);
+    }
+
+    let Some(jong) = before.jong else {
+        return;
+    };
+    let from = (before.jong, after.cho);
+
+    // ㄱ ㄷ ㅂ ㅈ followed by ㅎ are aspirated (제12항), ㄷ ㅌ before 히 also palatalize (제17항)
+    if after.cho == 'ㅎ' {
+        let changed = match jong {
+            'ㄱ' | 'ㄲ' => Some((None, 'ㅋ')),
+            'ㄺ' => Some((Some('ㄹ'), 'ㅋ')),
+            'ㄷ' | 'ㅌ' if after.jung == 'ㅣ' => Some((None, 'ㅊ')),
+            'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅌ' => Some((None, 'ㅌ')),
+            'ㅈ' | 'ㅊ' => Some((None, 'ㅊ')),
+            'ㄵ' => Some((Some('ㄴ'), 'ㅊ')),
+            'ㅂ' | 'ㅍ' => Some((None, 'ㅍ')),
+            'ㄼ' => Some((Some('ㄹ'), 'ㅍ')),
+            _ => None,
+        };
+        if let Some((kept, cho)) = changed {
+            before.jong = kept;
+            after.cho = cho;
+            let rule = if cho == 'ㅊ' && matches!(jong, 'ㄷ' | 'ㅌ') {
+                SoundRule::Palatalization
+            } else {
+                SoundRule::Aspiration
+            };
+            trace.record(rule, from, before, after);
+            return;
+        }
+    }
+
+    if after.cho == 'ㅇ' {
+        // palatalization (제17항): ㄷ ㅌ before 이 become ㅈ ㅊ
+        let palatal = match jong {
+            'ㄷ' => Some((None, 'ㅈ')),
+            'ㅌ' => Some((None, 'ㅊ')),
+            'ㄾ' => Some((Some('ㄹ'), 'ㅊ')),
+            _ => None,
+        };
+        if let (Some((kept, cho)), 'ㅣ') = (palatal, after.jung) {
+            before.jong = kept;
+            after.cho = cho;
+            trace.record(SoundRule::Palatalization, from, before, after);
+            return;
+        }
+
+        // linking (제13항, 제14항): the final moves over to the empty initial
+        if jong == 'ㅇ' {
+            return;
+        }
+        match split_double_consonant(jong) {
+            Some((kept, moved)) => {
+                before.jong = Some(kept);
+                after.cho = if moved == 'ㅅ' && rules.tensification {
+                    'ㅆ'
+                } else {
+                    moved
+                };
+            }
+            None => {
+                before.jong = None;
+                after.cho = jong;
+            }
+        }
+        trace.record(SoundRule::Linking, from, before, after);
+        return;
+    }
+
+    // final neutralization and compound final simplification (제9항 ~ 제11항)
+    let final_sound = resolve_final(before, jong, Some(after));
+    before.jong = Some(final_sound);
+    trace.record(neutralization_rule(jong), from, before, after);
+
+    // tensification (제23항, 제24항, 제25항)
+    if rules.tensification
+        && (matches!(final_sound, 'ㄱ' | 'ㄷ' | 'ㅂ')
+            || matches!(jong, 'ㄵ' | 'ㄺ' | 'ㄻ' | 'ㄼ' | 'ㄾ'))
+    {
+        let from = (before.jong, after.cho);
+        after.cho = tense(after.cho);
+        trace.record(SoundRule::Tensification, from, before, after);
+    }
+
+    // ㄹ after a final other than ㄹ is pronounced ㄴ (제19항)
+    if after.cho == 'ㄹ' && matches!(final_sound, 'ㄱ' | 'ㄷ' | 'ㅂ' | 'ㅁ' | 'ㅇ') {
+        let from = (before.jong, after.cho);
+        after.cho = 'ㄴ';
+        trace.record(SoundRule::RieulNasalization, from, before, after);
+    }
+
+    // nasalization (제18항)
+    if matches!(after.cho, 'ㄴ' | 'ㅁ') {
+        let from = (before.jong, after.cho);
+        before.jong = Some(match final_sound {
+            'ㄱ' => 'ㅇ',
+            'ㄷ' => 'ㄴ',
+            'ㅂ' => 'ㅁ',
+            _ => final_sound,
+        });
+        trace.record(SoundRule::Nasalization, from, before, after);
+    }
+
+    // liquidization (제20항), but ㄹ of a suffix after ㄴ is pronounced ㄴ (의견란 → 의견난)
+    let from = (before.jong, after.cho);
+    match (before.jong, after.cho) {
+        (Some('ㄴ'), 'ㄹ') if suffix => after.cho = 'ㄴ',
+        (Some('ㄴ'), 'ㄹ') => before.jong = Some('ㄹ'),
+        (Some('ㄹ'), 'ㄴ') => after.cho = 'ㄹ',
+        _ => {}
+    }
+    trace.record(SoundRule::Liquidization, from, before, after);
+}
@@ -0,0 +1,1285 @@
+use crate::compose_korean::JUNGSEONG;
+use crate::enums::{Hyphenation, NameSeparator};
+use crate::pronunciation::{
+    compounds, pronounce_word, representative_final, segments, PronunciationDictionary, Segment,
+    SoundRules, Syllable,
+};
+
+/// Options for `romanize_with`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RomanizeOptions {
+    /// Romanize the pronunciation (신라 → silla) instead of each syllable on its own (sinra).
+    pub pronunciation: bool,
+    /// Where to put hyphens between the syllables of a word.
+    pub hyphenation: Hyphenation,
+}
+
+impl Default for RomanizeOptions {
+    fn default() -> Self {
+        RomanizeOptions {
+            pronunciation: true,
+            hyphenation: Hyphenation::None,
+        }
+    }
+}
+
+/// Romanizes Korean text with the Revised Romanization of Korean (국어의 로마자 표기법, 2000).
+///
+/// Sound changes such as linking, nasalization, liquidization, palatalization and aspiration
+/// are written as they are pronounced; tensification is not written. A '+' between the parts
+/// of a compound adds ㄴ like `pronounce` does (서울+역 → seoullyeok) and is left out. Other
+/// characters that are not Hangul syllables are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` to romanize.
+///
+/// # Returns
+/// * `String` - The romanized text.
+///
+/// # Examples
+/// ```
+/// use rustkorean::romanize;
+///
+/// assert_eq!(romanize("신라"), "silla");
+/// assert_eq!(romanize("종로"), "jongno");
+/// assert_eq!(romanize("같이"), "gachi");
+/// assert_eq!(romanize("한국어 2024"), "hangugeo 2024");
+/// ```
+pub fn romanize(text: &str) -> String {
+    romanize_with(text, RomanizeOptions::default())
+}
+
+/// Romanizes Korean text with the Revised Romanization of Korean using the given options.
+///
+/// # Arguments
+/// * `text` - A `&str` to romanize.
+/// * `options` - The `RomanizeOptions` to romanize with.
+///
+/// # Returns
+/// * `String` - The romanized text.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{romanize_with, Hyphenation, RomanizeOptions};
+///
+/// let per_syllable = RomanizeOptions { pronunciation: false, ..Default::default() };
+/// assert_eq!(romanize_with("신라", per_syllable), "sinra");
+///
+/// let hyphenated = RomanizeOptions { hyphenation: Hyphenation::Ambiguous, ..Default::default() };
+/// assert_eq!(romanize_with("중앙", hyphenated), "jung-ang");
+/// assert_eq!(romanize_with("해운대", hyphenated), "hae-undae");
+/// ```
+pub fn romanize_with(text: &str, options: RomanizeOptions) -> String {
+    revised_romanization(text, options, None)
+}
+
+fn revised_romanization(
+    text: &str,
+    options: RomanizeOptions,
+    dictionary: Option<&PronunciationDictionary>,
+) -> String {
+    let mut result = String::new();
+
+    for (segment, boundaries) in compounds(text) {
+        match segment {
+            Segment::Word(mut word) => {
+                if options.pronunciation {
+                    pronounce_word(&mut word, SoundRules::default(), &boundaries, dictionary);
+                }
+                result.push_str(&romanize_word(&word, options.hyphenation));
+            }
+            Segment::Other(other) => result.push_str(&other),
+        }
+    }
+
+    result
+}
+
+impl PronunciationDictionary {
+    /// Romanizes Korean text like `romanize`, taking the preferred pronunciation of every word
+    /// found in the dictionary instead of applying the rules to it. Tensification in those
+    /// pronunciations is not written, as the Revised Romanization does not write it.
+    ///
+    /// # Examples
+    /// ```
+    /// use rustkorean::PronunciationDictionary;
+    ///
+    /// let dictionary = PronunciationDictionary::standard();
+    /// assert_eq!(dictionary.romanize("맛있다"), "maditda");
+    /// assert_eq!(dictionary.romanize("인기 교과서"), "ingi gyogwaseo");
+    /// ```
+    pub fn romanize(&self, text: &str) -> String {
+        revised_romanization(text, RomanizeOptions::default(), Some(self))
+    }
+
+    /// Romanizes Korean text like `romanize_mccune_reischauer`, taking the preferred
+    /// pronunciation of every word found in the dictionary instead of applying the rules to it.
+    ///
+    /// # Examples
+    /// ```
+    /// use rustkorean::PronunciationDictionary;
+    ///
+    /// let dictionary = PronunciationDictionary::standard();
+    /// assert_eq!(dictionary.romanize_mccune_reischauer("갈등"), "kalttŭng");
+    /// ```
+    pub fn romanize_mccune_reischauer(&self, text: &str) -> String {
+        mccune_reischauer(text, Some(self))
+    }
+
+    /// Transcribes Korean text like `to_cyrillic`, taking the preferred pronunciation of every
+    /// word found in the dictionary instead of applying the rules to it.
+    ///
+    /// # Examples
+    /// ```
+    /// use rustkorean::PronunciationDictionary;
+    ///
+    /// let dictionary = PronunciationDictionary::standard();
+    /// assert_eq!(dictionary.to_cyrillic("맛있다"), "Мадитта");
+    /// ```
+    pub fn to_cyrillic(&self, text: &str) -> String {
+        cyrillic(text, Some(self))
+    }
+
+    /// Transcribes Korean text like `to_ipa`, taking the preferred pronunciation of every word
+    /// found in the dictionary instead of applying the rules to it.
+    ///
+    /// # Examples
+    /// ```
+    /// use rustkorean::PronunciationDictionary;
+    ///
+    /// let dictionary = PronunciationDictionary::standard();
+    /// assert_eq!(dictionary.to_ipa("인기"), "ink͈i");
+    /// ```
+    pub fn to_ipa(&self, text: &str) -> String {
+        ipa(text, Some(self))
+    }
+}
+
+/// Conventional spellings of common surnames used in passports instead of their Revised
+/// Romanization, with the two-syllable surnames first.
+const SURNAMES: [(&str, &str); 110] = [
+    ("남궁", "Namgung"),
+    ("황보", "Hwangbo"),
+    ("제갈", "Jegal"),
+    ("선우", "Sunwoo"),
+    ("독고", "Dokgo"),
+    ("사공", "Sagong"),
+    ("서문", "Seomun"),
+    ("동방", "Dongbang"),
+    ("김", "Kim"),
+    ("이", "Lee"),
+    ("리", "Lee"),
+    ("박", "Park"),
+    ("최", "Choi"),
+    ("정", "Jung"),
+    ("강", "Kang"),
+    ("조", "Cho"),
+    ("윤", "Yoon"),
+    ("장", "Jang"),
+    ("임", "Lim"),
+    ("림", "Lim"),
+    ("한", "Han"),
+    ("오", "Oh"),
+    ("서", "Seo"),
+    ("신", "Shin"),
+    ("권", "Kwon"),
+    ("황", "Hwang"),
+    ("안", "Ahn"),
+    ("송", "Song"),
+    ("류", "Ryu"),
+    ("유", "Yoo"),
+    ("홍", "Hong"),
+    ("전", "Jeon"),
+    ("고", "Ko"),
+    ("문", "Moon"),
+    ("양", "Yang"),
+    ("손", "Son"),
+    ("배", "Bae"),
+    ("백", "Baek"),
+    ("허", "Heo"),
+    ("노", "Noh"),
+    ("로", "Roh"),
+    ("심", "Shim"),
+    ("하", "Ha"),
+    ("곽", "Kwak"),
+    ("성", "Sung"),
+    ("차", "Cha"),
+    ("주", "Joo"),
+    ("우", "Woo"),
+    ("구", "Koo"),
+    ("나", "Na"),
+    ("라", "Ra"),
+    ("민", "Min"),
+    ("진", "Jin"),
+    ("지", "Ji"),
+    ("엄", "Um"),
+    ("채", "Chae"),
+    ("원", "Won"),
+    ("천", "Chun"),
+    ("방", "Bang"),
+    ("공", "Kong"),
+    ("현", "Hyun"),
+    ("함", "Ham"),
+    ("변", "Byun"),
+    ("염", "Yeom"),
+    ("여", "Yeo"),
+    ("추", "Choo"),
+    ("도", "Do"),
+    ("소", "So"),
+    ("석", "Seok"),
+    ("선", "Sun"),
+    ("설", "Seol"),
+    ("마", "Ma"),
+    ("길", "Gil"),
+    ("연", "Yeon"),
+    ("위", "Wi"),
+    ("표", "Pyo"),
+    ("명", "Myung"),
+    ("기", "Ki"),
+    ("반", "Ban"),
+    ("왕", "Wang"),
+    ("금", "Keum"),
+    ("옥", "Ok"),
+    ("육", "Yuk"),
+    ("인", "In"),
+    ("맹", "Maeng"),
+    ("제", "Je"),
+    ("모", "Mo"),
+    ("남", "Nam"),
+    ("탁", "Tak"),
+    ("국", "Kook"),
+    ("어", "Eo"),
+    ("은", "Eun"),
+    ("편", "Pyeon"),
+    ("용", "Yong"),
+    ("예", "Ye"),
+    ("경", "Kyung"),
+    ("봉", "Bong"),
+    ("부", "Boo"),
+    ("가", "Ka"),
+    ("태", "Tae"),
+    ("목", "Mok"),
+    ("형", "Hyung"),
+    ("계", "Kye"),
+    ("피", "Pi"),
+    ("두", "Doo"),
+    ("감", "Kam"),
+    ("빈", "Bin"),
+    ("동", "Dong"),
+    ("호", "Ho"),
+    ("승", "Seung"),
+];
+
+/// Romanizes a Korean personal name the way it is written in a passport.
+///
+/// Common surnames take their conventional spelling (김 → Kim, 이 → Lee, 박 → Park) and
+/// two-syllable surnames (남궁, 황보, 제갈 …) are recognized; other surnames fall back to the
+/// Revised Romanization. The given name is romanized syllable by syllable without sound
+/// changes (지혜 → Jihye, not Jihe), joined as `separator` says. A space in `name` marks where
+/// the surname ends, which is needed when a one-syllable surname looks like a two-syllable one.
+/// Names that are not all Hangul are romanized with `romanize`.
+///
+/// # Arguments
+/// * `name` - A `&str` holding the surname followed by the given name.
+/// * `separator` - The `NameSeparator` between the syllables of the given name.
+///
+/// # Returns
+/// * `String` - The surname and given name, capitalized and separated by a space.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{romanize_name, NameSeparator};
+///
+/// assert_eq!(romanize_name("김지혜", NameSeparator::None), "Kim Jihye");
+/// assert_eq!(romanize_name("이한라", NameSeparator::Hyphen), "Lee Han-ra");
+/// assert_eq!(romanize_name("남궁민수", NameSeparator::Space), "Namgung Min Su");
+/// assert_eq!(romanize_name("남 궁민", NameSeparator::None), "Nam Gungmin");
+/// ```
+pub fn romanize_name(name: &str, separator: NameSeparator) -> String {
+    let name = name.trim();
+    let (surname, given_name) = match name.split_once(char::is_whitespace) {
+        Some((surname, given_name)) => (surname, given_name.trim_start()),
+        None => {
+            let two_syllable = SURNAMES.iter().find(|&&(surname, _)| {
+                surname.chars().count() == 2
+                    && name.starts_with(surname)
+                    && name.chars().count() > 2
+            });
+            let length = match two_syllable {
+                Some((surname, _)) => surname.len(),
+                None => name.chars().next().map_or(0, char::len_utf8),
+            };
+            name.split_at(length)
+        }
+    };
+
+    let hangul = |text: &str| {
+        text.chars()
+            .map(Syllable::from_char)
+            .collect::<Option<Vec<_>>>()
+    };
+    let (Some(surname_syllables), Some(syllables)) = (hangul(surname), hangul(given_name)) else {
+        return romanize(name);
+    };
+    if surname_syllables.is_empty() {
+        return String::new();
+    }
+
+    let surname = match SURNAMES.iter().find(|&&(korean, _)| korean == surname) {
+        Some(&(_, roman)) => roman.to_string(),
+        None => capitalize(&romanize_word(&surname_syllables, Hyphenation::None)),
+    };
+
+    let given_name = match separator {
+        NameSeparator::None => capitalize(&romanize_word(&syllables, Hyphenation::None)),
+        NameSeparator::Hyphen => capitalize(&romanize_word(&syllables, Hyphenation::Syllables)),
+        NameSeparator::Space => syllables
+            .iter()
+            .map(|syllable| capitalize(&romanize_word(&[*syllable], Hyphenation::None)))
+            .collect::<Vec<_>>()
+            .join(" "),
+    };
+
+    if given_name.is_empty() {
+        surname
+    } else {
+        format!("{} {}", surname, given_name)
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Romanizes Korean text with the McCune–Reischauer system.
+///
+/// Vowels ㅓ and ㅡ are written with a breve (ŏ, ŭ) and aspirated consonants with an
+/// apostrophe (k', t', p', ch'). ㄱ ㄷ ㅂ ㅈ are written voiced (g, d, b, j) between voiced
+/// sounds and voiceless (k, t, p, ch) at the start of a word or after a stop. Sound changes,
+/// including tensification, are applied before romanizing. Characters that are not Hangul
+/// syllables are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` to romanize.
+///
+/// # Returns
+/// * `String` - The romanized text.
+///
+/// # Examples
+/// ```
+/// use rustkorean::romanize_mccune_reischauer;
+///
+/// assert_eq!(romanize_mccune_reischauer("부산"), "pusan");
+/// assert_eq!(romanize_mccune_reischauer("한국"), "han'guk");
+/// assert_eq!(romanize_mccune_reischauer("김치"), "kimch'i");
+/// assert_eq!(romanize_mccune_reischauer("압구정"), "apkujŏng");
+/// ```
+pub fn romanize_mccune_reischauer(text: &str) -> String {
+    mccune_reischauer(text, None)
+}
+
+fn mccune_reischauer(text: &str, dictionary: Option<&PronunciationDictionary>) -> String {
+    let mut result = String::new();
+
+    for (segment, boundaries) in compounds(text) {
+        match segment {
+            Segment::Word(mut word) => {
+                let rules = SoundRules {
+                    tensification: true,
+                };
+                pronounce_word(&mut word, rules, &boundaries, dictionary);
+                for (index, syllable) in word.iter().enumerate() {
+                    let previous = index.checked_sub(1).map(|previous| word[previous].jong);
+                    result.push_str(&mccune_reischauer_initial(syllable, previous));
+                    result.push_str(mccune_reischauer_medial(syllable.jung));
+                    if let Some(jong) = syllable.jong {
+                        result.push_str(final_consonant(representative_final(jong)));
+                    }
+                }
+            }
+            Segment::Other(other) => result.push_str(&other),
+        }
+    }
+
+    result
+}
+
+/// `previous` is `None` at the start of a word, otherwise the final of the syllable before.
+fn mccune_reischauer_initial(syllable: &Syllable, previous: Option<Option<char>>) -> String {
+    let after_voiced = matches!(previous, Some(None | Some('ㄴ' | 'ㄹ' | 'ㅁ' | 'ㅇ')));
+    let after_stop = matches!(previous, Some(Some('ㄱ' | 'ㄷ' | 'ㅂ')));
+
+    let initial = match syllable.cho {
+        'ㄱ' if after_voiced => "g",
+        'ㄷ' if after_voiced => "d",
+        'ㅂ' if after_voiced => "b",
+        'ㅈ' if after_voiced => "j",
+        'ㄱ' => "k",
+        'ㄷ' => "t",
+        'ㅂ' => "p",
+        'ㅈ' => "ch",
+        // tense consonants after a stop are written as the plain voiceless consonant
+        'ㄲ' if after_stop => "k",
+        'ㄸ' if after_stop => "t",
+        'ㅃ' if after_stop => "p",
+        'ㅉ' if after_stop => "ch",
+        'ㅆ' if after_stop => "s",
+        'ㄲ' => "kk",
+        'ㄸ' => "tt",
+        'ㅃ' => "pp",
+        'ㅉ' => "tch",
+        'ㅆ' => "ss",
+        'ㅋ' => "k'",
+        'ㅌ' => "t'",
+        'ㅍ' => "p'",
+        'ㅊ' => "ch'",
+        'ㅅ' if syllable.jung == 'ㅟ' => "sh",
+        'ㄹ' if previous == Some(Some('ㄹ')) => "l",
+        cho => initial(cho),
+    };
+
+    // an apostrophe keeps n + g apart from ng
+    if previous == Some(Some('ㄴ')) && initial.starts_with('g') {
+        format!("'{}", initial)
+    } else {
+        initial.to_string()
+    }
+}
+
+fn mccune_reischauer_medial(jung: char) -> &'static str {
+    match jung {
+        'ㅓ' => "ŏ",
+        'ㅕ' => "yŏ",
+        'ㅝ' => "wŏ",
+        'ㅡ' => "ŭ",
+        'ㅢ' => "ŭi",
+        _ => medial(jung),
+    }
+}
+
+/// Established Russian spellings of place names that differ from the Kontsevich system.
+const CYRILLIC_EXONYMS: [(&str, &str); 2] = [("서울", "Сеул"), ("평양", "Пхеньян")];
+
+/// Transcribes Korean text into Cyrillic with the Kontsevich system (система Концевича).
+///
+/// ㄱ ㄷ ㅂ ㅈ are written voiced (г, д, б, дж) between voiced sounds and voiceless (к, т, п, ч)
+/// elsewhere, aspirated consonants with х (кх, тх, пх, чх), a final ㄹ as ль and a final ㅇ as н
+/// (нъ before a vowel). Sound changes are applied before transcribing, and each Korean word is
+/// capitalized as names and place names are. Established exonyms such as 서울 → Сеул are used as
+/// they are. Characters that are not Hangul syllables are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` to transcribe.
+///
+/// # Returns
+/// * `String` - The transcribed text.
+///
+/// # Examples
+/// ```
+/// use rustkorean::to_cyrillic;
+///
+/// assert_eq!(to_cyrillic("한국"), "Хангук");
+/// assert_eq!(to_cyrillic("서울"), "Сеул");
+/// assert_eq!(to_cyrillic("부산"), "Пусан");
+/// assert_eq!(to_cyrillic("김치"), "Кимчхи");
+/// ```
+pub fn to_cyrillic(text: &str) -> String {
+    cyrillic(text, None)
+}
+
+fn cyrillic(text: &str, dictionary: Option<&PronunciationDictionary>) -> String {
+    let mut result = String::new();
+
+    for (segment, boundaries) in compounds(text) {
+        match segment {
+            Segment::Word(mut word) => {
+                let spelled: String = word.iter().map(|syllable| syllable.to_char()).collect();
+                if let Some(&(_, exonym)) = CYRILLIC_EXONYMS
+                    .iter()
+                    .find(|&&(korean, _)| korean == spelled)
+                {
+                    result.push_str(exonym);
+                    continue;
+                }
+
+                pronounce_word(&mut word, SoundRules::default(), &boundaries, dictionary);
+                let mut cyrillic = String::new();
+                for (index, syllable) in word.iter().enumerate() {
+                    let previous = index.checked_sub(1).map(|previous| word[previous].jong);
+                    let next = word.get(index + 1);
+                    cyrillic.push_str(cyrillic_initial(syllable.cho, previous));
+                    cyrillic.push_str(cyrillic_medial(syllable.cho, syllable.jung));
+                    if let Some(jong) = syllable.jong {
+                        cyrillic.push_str(cyrillic_final(representative_final(jong), next));
+                    }
+                }
+                result.push_str(&capitalize(&cyrillic));
+            }
+            Segment::Other(other) => result.push_str(&other),
+        }
+    }
+
+    result
+}
+
+/// `previous` is `None` at the start of a word, otherwise the final of the syllable before.
+fn cyrillic_initial(cho: char, previous: Option<Option<char>>) -> &'static str {
+    let voiced = matches!(previous, Some(None | Some('ㄴ' | 'ㄹ' | 'ㅁ' | 'ㅇ')));
+
+    match cho {
+        'ㄱ' if voiced => "г",
+        'ㄷ' if voiced => "д",
+        'ㅂ' if voiced => "б",
+        'ㅈ' if voiced => "дж",
+        'ㄱ' => "к",
+        'ㄲ' => "кк",
+        'ㄴ' => "н",
+        'ㄷ' => "т",
+        'ㄸ' => "тт",
+        'ㄹ' if previous == Some(Some('ㄹ')) => "л",
+        'ㄹ' => "р",
+        'ㅁ' => "м",
+        'ㅂ' => "п",
+        'ㅃ' => "пп",
+        'ㅅ' => "с",
+        'ㅆ' => "сс",
+        'ㅈ' => "ч",
+        'ㅉ' => "чч",
+        'ㅊ' => "чх",
+        'ㅋ' => "кх",
+        'ㅌ' => "тх",
+        'ㅍ' => "пх",
+        'ㅎ' => "х",
+        _ => "",
+    }
+}
+
+fn cyrillic_medial(cho: char, jung: char) -> &'static str {
+    let after_consonant = cho != 'ㅇ';
+
+    match jung {
+        'ㅏ' => "а",
+        'ㅐ' => "э",
+        'ㅑ' => "я",
+        'ㅒ' if after_consonant => "я",
+        'ㅒ' => "йя",
+        'ㅓ' => "о",
+        'ㅔ' if after_consonant => "е",
+        'ㅔ' => "э",
+        'ㅕ' => "ё",
+        'ㅖ' if after_consonant => "е",
+        'ㅖ' => "йе",
+        'ㅗ' => "о",
+        'ㅘ' => "ва",
+        'ㅙ' => "вэ",
+        'ㅚ' => "ве",
+        'ㅛ' => "ё",
+        'ㅜ' => "у",
+        'ㅝ' => "во",
+        'ㅞ' => "ве",
+        'ㅟ' => "ви",
+        'ㅠ' => "ю",
+        'ㅡ' => "ы",
+        'ㅢ' if after_consonant => "и",
+        'ㅢ' => "ый",
+        _ => "и",
+    }
+}
+
+/// `next` is the following syllable, `None` at the end of a word.
+fn cyrillic_final(jong: char, next: Option<&Syllable>) -> &'static str {
+    let next_cho = next.map(|next| next.cho);
+    // ㅘ ㅙ ㅚ ㅝ ㅞ ㅟ start with в, so they do not take the separating ъ
+    let next_vowel = next.is_some_and(|next| {
+        next.cho == 'ㅇ' && !matches!(next.jung, 'ㅘ' | 'ㅙ' | 'ㅚ' | 'ㅝ' | 'ㅞ' | 'ㅟ')
+    });
+
+    match jong {
+        'ㄱ' => "к",
+        'ㄴ' => "н",
+        'ㄷ' => "т",
+        'ㄹ' if next_cho == Some('ㄹ') => "л",
+        'ㄹ' => "ль",
+        'ㅁ' => "м",
+        'ㅂ' => "п",
+        'ㅇ' if next_vowel => "нъ",
+        _ => "н",
+    }
+}
+
+/// Transcribes Korean text into broad IPA after applying the standard pronunciation rules.
+///
+/// Tense consonants are marked with a double vertical line below (p͈), aspirated ones with ʰ and
+/// the stops of a final with the unreleased mark (k̚). Lax stops and ㅈ are voiced between voiced
+/// sounds (바보 → pabo), ㄹ is [ɾ] before a vowel and [l] elsewhere, and ㅅ ㅆ are [ɕ] before i
+/// and y. Characters that are not Hangul syllables are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` to transcribe.
+///
+/// # Returns
+/// * `String` - The IPA transcription.
+///
+/// # Examples
+/// ```
+/// use rustkorean::to_ipa;
+///
+/// assert_eq!(to_ipa("국밥"), "kuk̚p͈ap̚");
+/// assert_eq!(to_ipa("바보"), "pabo");
+/// assert_eq!(to_ipa("김치"), "kimt͡ɕʰi");
+/// assert_eq!(to_ipa("한국어"), "hanɡuɡʌ");
+/// ```
+pub fn to_ipa(text: &str) -> String {
+    ipa(text, None)
+}
+
+fn ipa(text: &str, dictionary: Option<&PronunciationDictionary>) -> String {
+    let mut result = String::new();
+
+    for (segment, boundaries) in compounds(text) {
+        match segment {
+            Segment::Word(mut word) => {
+                let rules = SoundRules {
+                    tensification: true,
+                };
+                pronounce_word(&mut word, rules, &boundaries, dictionary);
+                for (index, syllable) in word.iter().enumerate() {
+                    let previous = index.checked_sub(1).map(|previous| word[previous].jong);
+                    result.push_str(ipa_initial(syllable, previous));
+                    result.push_str(ipa_medial(syllable.jung));
+                    if let Some(jong) = syllable.jong {
+                        result.push_str(ipa_final(representative_final(jong)));
+                    }
+                }
+            }
+            Segment::Other(other) => result.push_str(&other),
+        }
+    }
+
+    result
+}
+
+/// `previous` is `None` at the start of a word, otherwise the final of the syllable before.
+fn ipa_initial(syllable: &Syllable, previous: Option<Option<char>>) -> &'static str {
+    let voiced = matches!(previous, Some(None | Some('ㄴ' | 'ㄹ' | 'ㅁ' | 'ㅇ')));
+    let palatal = matches!(
+        syllable.jung,
+        'ㅣ' | 'ㅑ' | 'ㅒ' | 'ㅕ' | 'ㅖ' | 'ㅛ' | 'ㅠ' | 'ㅟ'
+    );
+
+    match syllable.cho {
+        'ㄱ' if voiced => "ɡ",
+        'ㄷ' if voiced => "d",
+        'ㅂ' if voiced => "b",
+        'ㅈ' if voiced => "d͡ʑ",
+        'ㄱ' => "k",
+        'ㄲ' => "k͈",
+        'ㅋ' => "kʰ",
+        'ㄷ' => "t",
+        'ㄸ' => "t͈",
+        'ㅌ' => "tʰ",
+        'ㅂ' => "p",
+        'ㅃ' => "p͈",
+        'ㅍ' => "pʰ",
+        'ㅈ' => "t͡ɕ",
+        'ㅉ' => "t͡ɕ͈",
+        'ㅊ' => "t͡ɕʰ",
+        'ㅅ' if palatal => "ɕ",
+        'ㅅ' => "s",
+        'ㅆ' if palatal => "ɕ͈",
+        'ㅆ' => "s͈",
+        'ㄴ' => "n",
+        'ㅁ' => "m",
+        'ㄹ' if previous == Some(Some('ㄹ')) => "l",
+        'ㄹ' => "ɾ",
+        'ㅎ' => "h",
+        _ => "",
+    }
+}
+
+fn ipa_medial(jung: char) -> &'static str {
+    match jung {
+        'ㅏ' => "a",
+        'ㅐ' => "ɛ",
+        'ㅑ' => "ja",
+        'ㅒ' => "jɛ",
+        'ㅓ' => "ʌ",
+        'ㅔ' => "e",
+        'ㅕ' => "jʌ",
+        'ㅖ' => "je",
+        'ㅗ' => "o",
+        'ㅘ' => "wa",
+        'ㅙ' => "wɛ",
+        'ㅚ' => "we",
+        'ㅛ' => "jo",
+        'ㅜ' => "u",
+        'ㅝ' => "wʌ",
+        'ㅞ' => "we",
+        'ㅟ' => "wi",
+        'ㅠ' => "ju",
+        'ㅡ' => "ɯ",
+        'ㅢ' => "ɰi",
+        _ => "i",
+    }
+}
+
+fn ipa_final(jong: char) -> &'static str {
+    match jong {
+        'ㄱ' => "k̚",
+        'ㄷ' => "t̚",
+        'ㅂ' => "p̚",
+        'ㄴ' => "n",
+        'ㄹ' => "l",
+        'ㅁ' => "m",
+        _ => "ŋ",
+    }
+}
+
+/// Romanizes the syllables of one word as they are, without applying any sound change.
+pub(crate) fn romanize_word(word: &[Syllable], hyphenation: Hyphenation) -> String {
+    let mut result = String::new();
+    let mut previous = String::new();
+
+    for (index, syllable) in word.iter().enumerate() {
+        // ㄹㄹ is written ll
+        let initial = if index > 0 && syllable.cho == 'ㄹ' && word[index - 1].jong == Some('ㄹ') {
+            "l"
+        } else {
+            initial(syllable.cho)
+        };
+        let mut roman = format!("{}{}", initial, medial(syllable.jung));
+        if let Some(jong) = syllable.jong {
+            roman.push_str(final_consonant(representative_final(jong)));
+        }
+
+        if index > 0 && needs_hyphen(hyphenation, &previous, &roman) {
+            result.push('-');
+        }
+        result.push_str(&roman);
+        previous = roman;
+    }
+
+    result
+}
+
+fn needs_hyphen(hyphenation: Hyphenation, previous: &str, next: &str) -> bool {
+    match hyphenation {
+        Hyphenation::None => false,
+        Hyphenation::Syllables => true,
+        // 중앙 jung-ang, 해운대 hae-undae, 반구대 ban-gudae
+        Hyphenation::Ambiguous => {
+            next.starts_with(['a', 'e', 'i', 'o', 'u', 'w', 'y'])
+                || (previous.ends_with('n') && next.starts_with('g'))
+        }
+    }
+}
+
+fn initial(cho: char) -> &'static str {
+    match cho {
+        'ㄱ' => "g",
+        'ㄲ' => "kk",
+        'ㄴ' => "n",
+        'ㄷ' => "d",
+        'ㄸ' => "tt",
+        'ㄹ' => "r",
+        'ㅁ' => "m",
+        'ㅂ' => "b",
+        'ㅃ' => "pp",
+        'ㅅ' => "s",
+        'ㅆ' => "ss",
+        'ㅈ' => "j",
+        'ㅉ' => "jj",
+        'ㅊ' => "ch",
+        'ㅋ' => "k",
+        'ㅌ' => "t",
+        'ㅍ' => "p",
+        'ㅎ' => "h",
+        _ => "",
+    }
+}
+
+fn medial(jung: char) -> &'static str {
+    match jung {
+        'ㅏ' => "a",
+        'ㅐ' => "ae",
+        'ㅑ' => "ya",
+        'ㅒ' => "yae",
+        'ㅓ' => "eo",
+        'ㅔ' => "e",
+        'ㅕ' => "yeo",
+        'ㅖ' => "ye",
+        'ㅗ' => "o",
+        'ㅘ' => "wa",
+        'ㅙ' => "wae",
+        'ㅚ' => "oe",
+        'ㅛ' => "yo",
+        'ㅜ' => "u",
+        'ㅝ' => "wo",
+        'ㅞ' => "we",
+        'ㅟ' => "wi",
+        'ㅠ' => "yu",
+        'ㅡ' => "eu",
+        'ㅢ' => "ui",
+        _ => "i",
+    }
+}
+
+fn final_consonant(jong: char) -> &'static str {
+    match jong {
+        'ㄱ' => "k",
+        'ㄴ' => "n",
+        'ㄷ' => "t",
+        'ㄹ' => "l",
+        'ㅁ' => "m",
+        'ㅂ' => "p",
+        'ㅇ' => "ng",
+        _ => "",
+    }
+}
+
+/// Yale spellings of the initial consonants; ㅇ is not written.
+const YALE_INITIALS: [(char, &str); 19] = [
+    ('ㄱ', "k"),
+    ('ㄲ', "kk"),
+    ('ㄴ', "n"),
+    ('ㄷ', "t"),
+    ('ㄸ', "tt"),
+    ('ㄹ', "l"),
+    ('ㅁ', "m"),
+    ('ㅂ', "p"),
+    ('ㅃ', "pp"),
+    ('ㅅ', "s"),
+    ('ㅆ', "ss"),
+    ('ㅇ', ""),
+    ('ㅈ', "c"),
+    ('ㅉ', "cc"),
+    ('ㅊ', "ch"),
+    ('ㅋ', "kh"),
+    ('ㅌ', "th"),
+    ('ㅍ', "ph"),
+    ('ㅎ', "h"),
+];
+
+/// Yale spellings of the vowels.
+const YALE_VOWELS: [(char, &str); 21] = [
+    ('ㅏ', "a"),
+    ('ㅐ', "ay"),
+    ('ㅑ', "ya"),
+    ('ㅒ', "yay"),
+    ('ㅓ', "e"),
+    ('ㅔ', "ey"),
+    ('ㅕ', "ye"),
+    ('ㅖ', "yey"),
+    ('ㅗ', "o"),
+    ('ㅘ', "wa"),
+    ('ㅙ', "way"),
+    ('ㅚ', "oy"),
+    ('ㅛ', "yo"),
+    ('ㅜ', "wu"),
+    ('ㅝ', "we"),
+    ('ㅞ', "wey"),
+    ('ㅟ', "wi"),
+    ('ㅠ', "yu"),
+    ('ㅡ', "u"),
+    ('ㅢ', "uy"),
+    ('ㅣ', "i"),
+];
+
+/// Yale spellings of the final consonants.
+const YALE_FINALS: [(char, &str); 27] = [
+    ('ㄱ', "k"),
+    ('ㄲ', "kk"),
+    ('ㄳ', "ks"),
+    ('ㄴ', "n"),
+    ('ㄵ', "nc"),
+    ('ㄶ', "nh"),
+    ('ㄷ', "t"),
+    ('ㄹ', "l"),
+    ('ㄺ', "lk"),
+    ('ㄻ', "lm"),
+    ('ㄼ', "lp"),
+    ('ㄽ', "ls"),
+    ('ㄾ', "lth"),
+    ('ㄿ', "lph"),
+    ('ㅀ', "lh"),
+    ('ㅁ', "m"),
+    ('ㅂ', "p"),
+    ('ㅄ', "ps"),
+    ('ㅅ', "s"),
+    ('ㅆ', "ss"),
+    ('ㅇ', "ng"),
+    ('ㅈ', "c"),
+    ('ㅊ', "ch"),
+    ('ㅋ', "kh"),
+    ('ㅌ', "th"),
+    ('ㅍ', "ph"),
+    ('ㅎ', "h"),
+];
+
+/// Romanizes Korean text with the Yale romanization.
+///
+/// Yale spells each syllable letter by letter without applying sound changes, so it can be
+/// turned back into Hangul with `from_yale`. A period separates two syllables wherever the
+/// boundary would otherwise be read differently (한아 → han.a, 하나 → hana).
+/// Characters that are not Hangul syllables are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` to romanize.
+///
+/// # Returns
+/// * `String` - The Yale romanization.
+///
+/// # Examples
+/// ```
+/// use rustkorean::to_yale;
+///
+/// assert_eq!(to_yale("한글"), "hankul");
+/// assert_eq!(to_yale("하나"), "hana");
+/// assert_eq!(to_yale("한아"), "han.a");
+/// assert_eq!(to_yale("읽어요"), "ilk.e.yo");
+/// ```
+pub fn to_yale(text: &str) -> String {
+    let mut result = String::new();
+
+    for segment in segments(text) {
+        match segment {
+            Segment::Word(word) => {
+                let mut yale = String::new();
+                for (index, syllable) in word.iter().enumerate() {
+                    let spelled = yale_syllable(syllable);
+                    let joined = format!("{}{}", yale, spelled);
+                    // a period is needed where the syllables would be read differently
+                    if parse_yale_word(&joined).as_deref() == Some(&word[..=index]) {
+                        yale = joined;
+                    } else {
+                        yale = format!("{}.{}", yale, spelled);
+                    }
+                }
+                result.push_str(&yale);
+            }
+            Segment::Other(other) => result.push_str(&other),
+        }
+    }
+
+    result
+}
+
+/// Converts Yale romanization back into Hangul. This is the inverse of `to_yale`.
+///
+/// Runs of lowercase letters, optionally split by periods between syllables, are read as
+/// Yale and composed into syllables. Every other character is kept as it is.
+///
+/// # Arguments
+/// * `text` - A `&str` in Yale romanization.
+///
+/// # Returns
+/// * `Option<String>` - The Hangul text, or `None` if a word is not valid Yale.
+///
+/// # Examples
+/// ```
+/// use rustkorean::from_yale;
+///
+/// assert_eq!(from_yale("hankwuk.e"), Some("한국어".to_string()));
+/// assert_eq!(from_yale("han.a, hana!"), Some("한아, 하나!".to_string()));
+/// assert_eq!(from_yale("xyz"), None);
+/// ```
+pub fn from_yale(text: &str) -> Option<String> {
+    let mut result = String::new();
+    let chars: Vec<char> = text.chars().collect();
+    let mut index = 0;
+
+    while index < chars.len() {
+        if !chars[index].is_ascii_lowercase() {
+            result.push(chars[index]);
+            index += 1;
+            continue;
+        }
+
+        // a word runs over lowercase letters and periods followed by another letter
+        let start = index;
+        while index < chars.len()
+            && (chars[index].is_ascii_lowercase()
+                || (chars[index] == '.'
+                    && chars.get(index + 1).is_some_and(|c| c.is_ascii_lowercase())))
+        {
+            index += 1;
+        }
+        let word: String = chars[start..index].iter().collect();
+
+        result.extend(parse_yale_word(&word)?.into_iter().map(Syllable::to_char));
+    }
+
+    Some(result)
+}
+
+fn yale_syllable(syllable: &Syllable) -> String {
+    let spell = |table: &[(char, &'static str)], jamo: char| {
+        table
+            .iter()
+            .find(|&&(entry, _)| entry == jamo)
+            .map_or("", |&(_, spelled)| spelled)
+    };
+
+    let mut result = String::new();
+    result.push_str(spell(&YALE_INITIALS, syllable.cho));
+    result.push_str(spell(&YALE_VOWELS, syllable.jung));
+    if let Some(jong) = syllable.jong {
+        result.push_str(spell(&YALE_FINALS, jong));
+    }
+    result
+}
+
+/// Parses one Yale word, with optional periods between syllables, into syllables.
+fn parse_yale_word(word: &str) -> Option<Vec<Syllable>> {
+    let mut result = Vec::new();
+    for part in word.split('.') {
+        result.extend(parse_yale_part(part)?);
+    }
+    Some(result)
+}
+
+/// Parses a run of Yale letters without periods. Consonants between two vowels are given
+/// to the following syllable as far as possible, the rest ends the syllable before.
+fn parse_yale_part(part: &str) -> Option<Vec<Syllable>> {
+    let is_vowel = |c: char| "aeiouwy".contains(c);
+    let lookup = |table: &[(char, &str)], spelled: &str| {
+        table
+            .iter()
+            .find(|&&(_, entry)| entry == spelled)
+            .map(|&(jamo, _)| jamo)
+    };
+
+    // split into alternating consonant and vowel runs
+    let mut runs: Vec<String> = Vec::new();
+    for character in part.chars() {
+        match runs.last_mut() {
+            Some(run) if run.chars().all(is_vowel) == is_vowel(character) => run.push(character),
+            _ => runs.push(character.to_string()),
+        }
+    }
+    if runs.is_empty() {
+        return None;
+    }
+    if runs[0].chars().all(is_vowel) {
+        runs.insert(0, String::new());
+    }
+
+    let mut result: Vec<Syllable> = Vec::new();
+    let mut onset = lookup(&YALE_INITIALS, &runs[0])?;
+    let mut index = 1;
+
+    while index < runs.len() {
+        // a run of vowels may hold several syllables without an initial consonant
+        let mut vowels = runs[index].as_str();
+        while !vowels.is_empty() {
+            let length = (1..=vowels.len())
+                .rev()
+                .find(|&length| lookup(&YALE_VOWELS, &vowels[..length]).is_some())?;
+            result.push(Syllable {
+                cho: onset,
+                jung: lookup(&YALE_VOWELS, &vowels[..length])?,
+                jong: None,
+            });
+            vowels = &vowels[length..];
+            onset = 'ㅇ';
+        }
+
+        let consonants = runs.get(index + 1).map_or("", String::as_str);
+        let last = result.last_mut()?;
+        if index + 2 >= runs.len() {
+            // the word ends with these consonants
+            if !consonants.is_empty() {
+                last.jong = Some(lookup(&YALE_FINALS, consonants)?);
+            }
+        } else {
+            let split = (0..=consonants.len()).find(|&split| {
+                lookup(&YALE_INITIALS, &consonants[split..]).is_some()
+                    && (split == 0 || lookup(&YALE_FINALS, &consonants[..split]).is_some())
+            })?;
+            if split > 0 {
+                last.jong = Some(lookup(&YALE_FINALS, &consonants[..split])?);
+            }
+            onset = lookup(&YALE_INITIALS, &consonants[split..])?;
+        }
+        index += 2;
+    }
+
+    Some(result)
+}
+
+/// Ways to read a Revised Romanization initial: (spelling, jamo, cost).
+const REVISED_INITIALS: [(&str, char, f32); 20] = [
+    ("g", 'ㄱ', 0.0),
+    ("kk", 'ㄲ', 0.0),
+    ("n", 'ㄴ', 0.0),
+    ("d", 'ㄷ', 0.0),
+    ("tt", 'ㄸ', 0.0),
+    ("r", 'ㄹ', 0.0),
+    ("l", 'ㄹ', 0.3),
+    ("m", 'ㅁ', 0.0),
+    ("b", 'ㅂ', 0.0),
+    ("pp", 'ㅃ', 0.0),
+    ("s", 'ㅅ', 0.0),
+    ("ss", 'ㅆ', 0.0),
+    ("", 'ㅇ', 0.0),
+    ("j", 'ㅈ', 0.0),
+    ("jj", 'ㅉ', 0.0),
+    ("ch", 'ㅊ', 0.0),
+    ("k", 'ㅋ', 0.0),
+    ("t", 'ㅌ', 0.0),
+    ("p", 'ㅍ', 0.0),
+    ("h", 'ㅎ', 0.0),
+];
+
+/// Ways to read a Revised Romanization final: (spelling, jamo, cost).
+/// A final written t, k or p can stand for several consonants, the common ones cost less.
+const REVISED_FINALS: [(&str, char, f32); 16] = [
+    ("k", 'ㄱ', 0.0),
+    ("k", 'ㄲ', 0.2),
+    ("k", 'ㅋ', 0.3),
+    ("n", 'ㄴ', 0.0),
+    ("t", 'ㅅ', 0.0),
+    ("t", 'ㄷ', 0.1),
+    ("t", 'ㅈ', 0.2),
+    ("t", 'ㅊ', 0.2),
+    ("t", 'ㅌ', 0.2),
+    ("t", 'ㅎ', 0.3),
+    ("l", 'ㄹ', 0.0),
+    ("m", 'ㅁ', 0.0),
+    ("p", 'ㅂ', 0.0),
+    ("p", 'ㅍ', 0.2),
+    ("ng", 'ㅇ', 0.0),
+    ("", ' ', 0.0),
+];
+
+/// How many partial readings `deromanize` keeps at each position.
+const DEROMANIZE_CANDIDATES: usize = 10;
+
+/// A partial reading: its cost and the syllables or other characters read so far.
+type Reading = (f32, Vec<Result<Syllable, char>>);
+
+/// Converts text in Revised Romanization back into Hangul candidates.
+///
+/// Romanized text can be read in more than one way ("hangang" may be 한강 or 항앙), so every
+/// reading is scored and the candidates are returned best first. Readings with fewer syllables
+/// and with consonants starting the next syllable rather than ending the previous one rank
+/// higher. Characters that are not ASCII letters are kept as they are.
+///
+/// # Arguments
+/// * `text` - A `&str` in Revised Romanization.
+///
+/// # Returns
+/// * `Vec<String>` - Hangul candidates, best first. Empty if the text has no letters or cannot be
+///   read as Korean.
+///
+/// # Examples
+/// ```
+/// use rustkorean::deromanize;
+///
+/// assert_eq!(deromanize("annyeonghaseyo")[0], "안녕하세요");
+/// assert_eq!(deromanize("gimchi")[0], "김치");
+/// assert_eq!(deromanize("hana")[0], "하나");
+/// assert!(deromanize("hangang").contains(&"한강".to_string()));
+/// ```
+pub fn deromanize(text: &str) -> Vec<String> {
+    let text = text.to_ascii_lowercase();
+    if !text.chars().any(|c| c.is_ascii_lowercase()) {
+        return Vec::new();
+    }
+    let chars: Vec<char> = text.chars().collect();
+
+    // readings[i] holds the best readings of chars[..i]
+    let mut readings: Vec<Vec<Reading>> = vec![Vec::new(); chars.len() + 1];
+    readings[0].push((0.0, Vec::new()));
+
+    for start in 0..chars.len() {
+        readings[start].sort_by(|a, b| a.0.total_cmp(&b.0));
+        readings[start].truncate(DEROMANIZE_CANDIDATES);
+        let current = std::mem::take(&mut readings[start]);
+
+        for (cost, tokens) in &current {
+            if !chars[start].is_ascii_lowercase() {
+                let mut next = tokens.clone();
+                next.push(Err(chars[start]));
+                readings[start + 1].push((*cost, next));
+                continue;
+            }
+
+            let previous = match tokens.last() {
+                Some(Ok(syllable)) => Some(*syllable),
+                _ => None,
+            };
+            for (end, syllable, syllable_cost) in revised_syllables(&chars, start, previous) {
+                let mut next = tokens.clone();
+                next.push(Ok(syllable));
+                readings[end].push((cost + syllable_cost, next));
+            }
+        }
+        readings[start] = current;
+    }
+
+    let mut finished = std::mem::take(&mut readings[chars.len()]);
+    finished.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut result: Vec<String> = Vec::new();
+    for (_, tokens) in finished {
+        let candidate: String = tokens
+            .into_iter()
+            .map(|token| token.map_or_else(|other| other, Syllable::to_char))
+            .collect();
+        if !result.contains(&candidate) {
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+/// Every Revised Romanization syllable that can start at `start`: (end, syllable, cost).
+fn revised_syllables(
+    chars: &[char],
+    start: usize,
+    previous: Option<Syllable>,
+) -> Vec<(usize, Syllable, f32)> {
+    let matches = |at: usize, spelled: &str| {
+        spelled
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| chars.get(at + offset) == Some(&c))
+    };
+    let vowels = JUNGSEONG.iter().map(|&jung| (medial(jung), jung));
+
+    let mut result = Vec::new();
+    for &(initial, cho, initial_cost) in REVISED_INITIALS.iter().filter(|e| matches(start, e.0)) {
+        // a syllable without an initial consonant inside a word is less likely than linking
+        let mut cost = 1.0 + initial_cost;
+        if cho == 'ㅇ' && previous.is_some() {
+            cost += 0.5;
+        }
+        if initial == "l" && previous.is_some_and(|p| p.jong == Some('ㄹ')) {
+            cost -= initial_cost;
+        }
+
+        let after_initial = start + initial.len();
+        for (vowel, jung) in vowels
+            .clone()
+            .filter(|&(vowel, _)| matches(after_initial, vowel))
+        {
+            let after_vowel = after_initial + vowel.len();
+            for &(spelled, jong, final_cost) in
+                REVISED_FINALS.iter().filter(|e| matches(after_vowel, e.0))
+            {
+                let syllable = Syllable {
+                    cho,
+                    jung,
+                    jong: (jong != ' ').then_some(jong),
+                };
+                result.push((after_vowel + spelled.len(), syllable, cost + final_cost));
+            }
+        }
+    }
+    result
+}
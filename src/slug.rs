@@ -0,0 +1,138 @@
+use crate::check_korean;
+use crate::enums::Hyphenation;
+use crate::pronunciation::{
+    compounds, pronounce_word, PronunciationDictionary, Segment, SoundRules,
+};
+use crate::romanization::romanize_word;
+
+/// Options for `slugify_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SlugOptions {
+    /// The longest slug to make, in characters. A syllable is never cut in half to fit.
+    pub max_length: Option<usize>,
+    /// Keep Hangul as it is instead of romanizing it, for slugs that are percent-encoded.
+    pub keep_hangul: bool,
+}
+
+/// Makes an ASCII URL slug from Korean text.
+///
+/// Hangul is romanized with the Revised Romanization, Latin letters are lowercased and digits
+/// are kept. Every run of whitespace, punctuation or other characters becomes a single hyphen,
+/// and the slug never starts or ends with a hyphen.
+///
+/// # Arguments
+/// * `text` - A `&str` such as an article title.
+///
+/// # Returns
+/// * `String` - The slug.
+///
+/// # Examples
+/// ```
+/// use rustkorean::slugify;
+///
+/// assert_eq!(slugify("서울 여행 가이드 2024!"), "seoul-yeohaeng-gaideu-2024");
+/// assert_eq!(slugify("  Rust로 만드는 한국어 처리  "), "rustro-mandeuneun-hangugeo-cheori");
+/// ```
+pub fn slugify(text: &str) -> String {
+    slugify_with(text, SlugOptions::default())
+}
+
+/// Makes a URL slug from Korean text using the given options.
+///
+/// # Arguments
+/// * `text` - A `&str` such as an article title.
+/// * `options` - The `SlugOptions` to make the slug with.
+///
+/// # Returns
+/// * `String` - The slug.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{slugify_with, SlugOptions};
+///
+/// let short = SlugOptions { max_length: Some(10), ..Default::default() };
+/// assert_eq!(slugify_with("서울 여행 가이드", short), "seoul-yeo");
+///
+/// let hangul = SlugOptions { keep_hangul: true, ..Default::default() };
+/// assert_eq!(slugify_with("서울 여행, Guide!", hangul), "서울-여행-guide");
+/// ```
+pub fn slugify_with(text: &str, options: SlugOptions) -> String {
+    slug(text, options, None)
+}
+
+impl PronunciationDictionary {
+    /// Makes a URL slug like `slugify_with`, romanizing the preferred pronunciation of every
+    /// word found in the dictionary instead of applying the rules to it.
+    ///
+    /// # Examples
+    /// ```
+    /// use rustkorean::{PronunciationDictionary, SlugOptions};
+    ///
+    /// let dictionary = PronunciationDictionary::standard();
+    /// assert_eq!(dictionary.slugify_with("맛있다 김밥", SlugOptions::default()), "maditda-gimbap");
+    /// ```
+    pub fn slugify_with(&self, text: &str, options: SlugOptions) -> String {
+        slug(text, options, Some(self))
+    }
+}
+
+fn slug(text: &str, options: SlugOptions, dictionary: Option<&PronunciationDictionary>) -> String {
+    // pieces that cannot be cut in half, `None` where a hyphen may go
+    let mut pieces: Vec<Option<String>> = Vec::new();
+
+    if options.keep_hangul {
+        for character in text.chars() {
+            pieces.push(if check_korean(character) {
+                Some(character.to_string())
+            } else {
+                slug_character(character)
+            });
+        }
+    } else {
+        for (segment, boundaries) in compounds(text) {
+            match segment {
+                Segment::Word(mut word) => {
+                    pronounce_word(&mut word, SoundRules::default(), &boundaries, dictionary);
+                    // the romanization of each syllable, taking the syllables before into account
+                    let mut romanized = 0;
+                    for end in 1..=word.len() {
+                        let prefix = romanize_word(&word[..end], Hyphenation::None);
+                        pieces.push(Some(prefix[romanized..].to_string()));
+                        romanized = prefix.len();
+                    }
+                }
+                Segment::Other(other) => pieces.extend(other.chars().map(slug_character)),
+            }
+        }
+    }
+
+    let mut slug = String::new();
+    let mut length = 0;
+    let mut hyphen = false;
+
+    for piece in pieces {
+        let Some(piece) = piece else {
+            hyphen = !slug.is_empty();
+            continue;
+        };
+
+        let added = usize::from(hyphen) + piece.chars().count();
+        if options.max_length.is_some_and(|max| length + added > max) {
+            break;
+        }
+        if hyphen {
+            slug.push('-');
+            hyphen = false;
+        }
+        slug.push_str(&piece);
+        length += added;
+    }
+
+    slug
+}
+
+fn slug_character(character: char) -> Option<String> {
+    character
+        .is_ascii_alphanumeric()
+        .then(|| character.to_ascii_lowercase().to_string())
+}
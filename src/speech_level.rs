@@ -0,0 +1,701 @@
+use crate::compose_korean::split_one_letter;
+use crate::conjugation::{conjugate, conjugation_type, syllable};
+use crate::enums::{ConjugationType, SpeechLevel};
+use crate::josa::strip_josa;
+
+/// Verb stems ending in 이 that are not the copula (보입니다 is not 보 + 입니다).
+const I_VERBS: [&str; 12] = [
+    "보이", "모이", "쓰이", "놓이", "먹이", "붙이", "줄이", "죽이", "속이", "끓이", "숙이", "높이",
+];
+
+/// Stems ending in 시 that are not the honorific 시 (마셨어요 is not 마 + 셨어요).
+const NATIVE_SI: [&str; 5] = ["마시", "모시", "드시", "계시", "가르치"];
+
+/// Stems ending in 우 whose -어 form 워 could be mistaken for a ㅂ irregular stem.
+const WU_STEMS: [&str; 12] = [
+    "배우", "싸우", "세우", "지우", "키우", "채우", "비우", "깨우", "피우", "태우", "외우", "가꾸",
+];
+
+/// Last syllables of ㅎ irregular adjectives, so that 파래 reads as 파랗다 rather than 파래다.
+const HIEUT_SYLLABLES: [char; 6] = ['랗', '렇', '떻', '얗', '맣', '갛'];
+
+/// Common adjective stems, which take 다 rather than ㄴ/는다 in the plain style.
+const ADJECTIVES: [&str; 26] = [
+    "좋", "싫", "많", "적", "크", "작", "높", "낮", "길", "짧", "넓", "멀", "같", "괜찮", "예쁘",
+    "바쁘", "아프", "기쁘", "슬프", "나쁘", "고프", "비싸", "싸", "짜", "달", "시",
+];
+
+/// Roots of common 하다 adjectives (필요하다), which 하다 verbs (공부하다) would otherwise hide.
+const HA_ADJECTIVES: [&str; 14] = [
+    "필요", "중요", "행복", "깨끗", "조용", "피곤", "건강", "따뜻", "시원", "유명", "친절", "가능",
+    "간단", "미안",
+];
+
+/// Endings that end in 요 or a vowel but are not the plain -아/어 form (먹네요, 먹지요).
+const OTHER_ENDINGS: [&str; 8] = ["네", "지", "죠", "거든", "잖아", "는데", "군", "게"];
+
+/// Sentence endings written in jamo, with the speech level they mark and how surely they mark
+/// it. Longer endings come first, so 했어 is read as 했어 before 어, and the bare vowels of the
+/// contracted -아/어 forms come last.
+const LEVEL_ENDINGS: [(&str, SpeechLevel, f32); 26] = [
+    ("ㅅㅣㅂㅅㅣㅇㅗ", SpeechLevel::Formal, 1.0),
+    ("ㅂㄴㅣㄷㅏ", SpeechLevel::Formal, 1.0),
+    ("ㅂㄴㅣㄲㅏ", SpeechLevel::Formal, 1.0),
+    ("ㅂㅅㅣㄷㅏ", SpeechLevel::Formal, 0.9),
+    ("ㅇㅛ", SpeechLevel::Polite, 0.9),
+    ("ㅈㅛ", SpeechLevel::Polite, 0.9),
+    ("ㅈㅏㄶㅇㅏ", SpeechLevel::Casual, 0.9),
+    ("ㄱㅓㄷㅡㄴ", SpeechLevel::Casual, 0.8),
+    ("ㄱㅜㄴㅏ", SpeechLevel::Casual, 0.8),
+    ("ㅆㅇㅓ", SpeechLevel::Casual, 0.9),
+    ("ㅇㅑ", SpeechLevel::Casual, 0.7),
+    ("ㅎㅐ", SpeechLevel::Casual, 0.7),
+    ("ㅈㅣ", SpeechLevel::Casual, 0.4),
+    ("ㄴㅔ", SpeechLevel::Casual, 0.4),
+    ("ㄴㄷㅏ", SpeechLevel::Plain, 0.9),
+    ("ㅆㄷㅏ", SpeechLevel::Plain, 0.9),
+    ("ㄴㅑ", SpeechLevel::Plain, 0.8),
+    ("ㄷㅏ", SpeechLevel::Plain, 0.6),
+    ("ㄴㅣ", SpeechLevel::Plain, 0.5),
+    ("ㅈㅏ", SpeechLevel::Plain, 0.4),
+    // the -아/어 ending, also contracted into an open syllable (먹어, 고마워, 가, 봐, 해)
+    ("ㅓ", SpeechLevel::Casual, 0.6),
+    ("ㅏ", SpeechLevel::Casual, 0.6),
+    ("ㅝ", SpeechLevel::Casual, 0.6),
+    ("ㅘ", SpeechLevel::Casual, 0.6),
+    ("ㅐ", SpeechLevel::Casual, 0.6),
+    ("ㅙ", SpeechLevel::Casual, 0.6),
+];
+
+/// How much a sentence whose level cannot be read weighs against the levels that are read.
+const UNREAD_WEIGHT: f32 = 0.5;
+
+/// What a sentence-final word says, without its ending.
+#[derive(Debug, Clone, PartialEq)]
+struct Predicate {
+    /// The noun before the copula (학생 in 학생입니다), empty for verbs and adjectives.
+    noun: String,
+    /// The stem without 시, 었 or 겠 (먹, 가, 이 for the copula, 아니 for 아니다).
+    stem: String,
+    kind: PredicateKind,
+    honorific: bool,
+    past: bool,
+    /// Whether the stem carries 겠 (알겠, 먹었겠).
+    future: bool,
+    question: bool,
+    /// The 한다체 question ending the predicate was read with (냐 in 먹었냐), written again at
+    /// that level instead of 니.
+    plain_question: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PredicateKind {
+    Verb,
+    Adjective,
+    Copula,
+}
+
+/// Rewrites the sentence-final endings of a text to another speech level.
+///
+/// Each sentence's last word is read as a predicate in any of the four levels, and rewritten
+/// with the same stem, tense, honorific 시 and question mark at `level`. Statements and
+/// questions are handled; imperatives read as statements. Sentences whose last word cannot be
+/// read as a predicate, or that are already at `level`, are left as they are. The stem is
+/// guessed from the surface form, so an ambiguous form takes its common reading (들어요 reads
+/// as 들다, not 듣다).
+///
+/// # Arguments
+/// * `text` - Korean text, one or more sentences.
+/// * `level` - The `SpeechLevel` to rewrite to.
+///
+/// # Returns
+/// * `String` - The text with its sentence endings rewritten.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{change_speech_level, SpeechLevel};
+///
+/// assert_eq!(change_speech_level("밥을 먹어요.", SpeechLevel::Formal), "밥을 먹습니다.");
+/// assert_eq!(change_speech_level("학교에 갑니까?", SpeechLevel::Polite), "학교에 가요?");
+/// assert_eq!(change_speech_level("저는 학생입니다.", SpeechLevel::Casual), "저는 학생이야.");
+/// assert_eq!(change_speech_level("어제 영화를 봤어요.", SpeechLevel::Plain), "어제 영화를 봤다.");
+/// assert_eq!(change_speech_level("비가 온다.", SpeechLevel::Polite), "비가 와요.");
+/// ```
+pub fn change_speech_level(text: &str, level: SpeechLevel) -> String {
+    rewrite_sentences(text, |word, question| {
+        let (predicate, current) = read_predicate(word, question)?;
+        if current == level {
+            return None;
+        }
+        Some(render(&predicate, level))
+    })
+}
+
+/// Adds or removes the honorific 시 in the sentence-final predicates of a text, keeping their
+/// speech level.
+///
+/// # Arguments
+/// * `text` - Korean text, one or more sentences.
+/// * `honorific` - `true` to add 시, `false` to remove it.
+///
+/// # Returns
+/// * `String` - The text with 시 added to or removed from its sentence endings.
+///
+/// # Examples
+/// ```
+/// use rustkorean::set_honorific;
+///
+/// assert_eq!(set_honorific("선생님이 책을 읽어요.", true), "선생님이 책을 읽으세요.");
+/// assert_eq!(set_honorific("어디 가십니까?", false), "어디 갑니까?");
+/// assert_eq!(set_honorific("할머니가 오셨다.", false), "할머니가 왔다.");
+/// ```
+pub fn set_honorific(text: &str, honorific: bool) -> String {
+    rewrite_sentences(text, |word, question| {
+        let (mut predicate, level) = read_predicate(word, question)?;
+        if predicate.kind == PredicateKind::Copula || predicate.honorific == honorific {
+            return None;
+        }
+        predicate.honorific = honorific;
+        Some(render(&predicate, level))
+    })
+}
+
+/// Detects the speech level of a text from its sentence endings.
+///
+/// The last syllables of each sentence's final word are taken apart into jamo and matched
+/// against endings such as ㅂ니다 (합쇼체), 요 (해요체), 어, 야 (해체) and 다, 냐 (한다체). A final
+/// word that is a noun with a particle (너는, 학교에) is passed over for the word before it.
+/// Every sentence votes for its level with how surely its ending marks it, and the confidence
+/// is the winning level's votes per sentence; sentences that cannot be read lower it.
+///
+/// # Arguments
+/// * `text` - Korean text, one or more sentences.
+///
+/// # Returns
+/// * `Option<(SpeechLevel, f32)>` - The speech level and a confidence between 0 and 1, or `None`
+///   if no sentence ending can be read.
+///
+/// # Examples
+/// ```
+/// use rustkorean::{detect_speech_level, SpeechLevel};
+///
+/// assert_eq!(detect_speech_level("감사합니다."), Some((SpeechLevel::Formal, 1.0)));
+/// assert_eq!(detect_speech_level("지금 어디 가요?").map(|(level, _)| level), Some(SpeechLevel::Polite));
+/// assert_eq!(detect_speech_level("밥 먹었어 너는?").map(|(level, _)| level), Some(SpeechLevel::Casual));
+/// assert_eq!(detect_speech_level("hello"), None);
+/// ```
+pub fn detect_speech_level(text: &str) -> Option<(SpeechLevel, f32)> {
+    let levels = [
+        SpeechLevel::Formal,
+        SpeechLevel::Polite,
+        SpeechLevel::Casual,
+        SpeechLevel::Plain,
+    ];
+    let mut votes = [0.0_f32; 4];
+    let mut sentences_read = 0.0_f32;
+    let mut unread = 0.0_f32;
+
+    for (body, punctuation) in sentences(text) {
+        if body.trim().is_empty() {
+            continue;
+        }
+        match sentence_level(body, punctuation.contains('?')) {
+            Some((level, weight)) => {
+                let index = levels.iter().position(|&known| known == level).unwrap_or(0);
+                votes[index] += weight;
+                sentences_read += 1.0;
+            }
+            None => unread += UNREAD_WEIGHT,
+        }
+    }
+
+    let (index, &best) = votes.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))?;
+    if best == 0.0 {
+        return None;
+    }
+    Some((levels[index], best / (sentences_read + unread)))
+}
+
+/// Reads the level of one sentence from its last word that is not a noun with a particle.
+fn sentence_level(body: &str, question: bool) -> Option<(SpeechLevel, f32)> {
+    for word in body.split_whitespace().rev() {
+        // ㅋㅋ, emoji and the like after the ending
+        let word = word.trim_end_matches(|c: char| split_one_letter(c).is_none());
+        if word.is_empty() {
+            continue;
+        }
+
+        let jamo: String = word
+            .chars()
+            .rev()
+            .take(3)
+            .collect::<Vec<char>>()
+            .into_iter()
+            .rev()
+            .filter_map(split_one_letter)
+            .flat_map(|(cho, jung, jong)| [Some(cho), Some(jung), jong])
+            .flatten()
+            .collect();
+        let ending = LEVEL_ENDINGS
+            .iter()
+            // 니 ends a question (먹니?) but also words such as 아니
+            .filter(|(ending, _, _)| question || *ending != "ㄴㅣ")
+            .find(|(ending, _, _)| jamo.ends_with(ending));
+
+        match ending {
+            Some(&(_, level, weight)) if weight >= 0.7 => return Some((level, weight)),
+            _ if is_noun_with_particle(word) => continue,
+            Some(&(_, level, weight)) => return Some((level, weight)),
+            None => return None,
+        }
+    }
+    None
+}
+
+fn is_noun_with_particle(word: &str) -> bool {
+    !strip_josa(word).1.is_empty()
+}
+
+/// Calls `rewrite` on the last word of each sentence, with whether the sentence ends in '?'.
+fn rewrite_sentences<F>(text: &str, mut rewrite: F) -> String
+where
+    F: FnMut(&str, bool) -> Option<String>,
+{
+    let mut result = String::new();
+
+    for (body, punctuation) in sentences(text) {
+        let trimmed = body.trim_end();
+        let word_start = trimmed.rfind(char::is_whitespace).map_or(0, |index| {
+            index + trimmed[index..].chars().next().map_or(1, char::len_utf8)
+        });
+        let word = &trimmed[word_start..];
+
+        match rewrite(word, punctuation.contains('?')) {
+            Some(rewritten) if !word.is_empty() => {
+                result.push_str(&trimmed[..word_start]);
+                result.push_str(&rewritten);
+                result.push_str(&body[trimmed.len()..]);
+            }
+            _ => result.push_str(body),
+        }
+        result.push_str(punctuation);
+    }
+
+    result
+}
+
+/// Splits a text into sentences, each as its body and the punctuation and spaces after it.
+fn sentences(text: &str) -> Vec<(&str, &str)> {
+    let is_terminator = |c: char| matches!(c, '.' | '?' | '!' | '…');
+    let mut sentences = Vec::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let body_end = rest.find(is_terminator).unwrap_or(rest.len());
+        let punctuation_end = rest[body_end..]
+            .find(|c: char| !is_terminator(c))
+            .map_or(rest.len(), |offset| body_end + offset);
+        sentences.push((&rest[..body_end], &rest[body_end..punctuation_end]));
+        rest = &rest[punctuation_end..];
+    }
+
+    sentences
+}
+
+/// Reads a sentence-final word as a predicate and the speech level it is in.
+fn read_predicate(word: &str, question: bool) -> Option<(Predicate, SpeechLevel)> {
+    if !word.chars().all(|c| split_one_letter(c).is_some()) {
+        return None;
+    }
+
+    // 합쇼체: 먹습니다, 갑니까
+    if let Some(front) = word
+        .strip_suffix("니다")
+        .or_else(|| word.strip_suffix("니까"))
+    {
+        let asked = question || word.ends_with("니까");
+        let stem = match front.strip_suffix('습') {
+            Some(stem) => stem.to_string(),
+            None => without_final(front, 'ㅂ')?,
+        };
+        let predicate = split_predicate(&stem, None, asked)?;
+        return Some((predicate, SpeechLevel::Formal));
+    }
+
+    // 해요체: 먹어요, 가세요, 학생이에요
+    if let Some(front) = word.strip_suffix('요') {
+        let predicate = read_copula(front, &["이에", "예", "에"], question)
+            .or_else(|| read_honorific_polite(front, question))
+            .or_else(|| read_infinitive(front, question))?;
+        return Some((predicate, SpeechLevel::Polite));
+    }
+
+    // 해라체: 먹는다, 간다, 좋다, 먹니?
+    if let Some(front) = word.strip_suffix('다') {
+        let predicate = if let Some(stem) = front.strip_suffix('는').filter(|s| !s.is_empty()) {
+            split_predicate(stem, Some(PredicateKind::Verb), question)?
+        } else if let Some(stem) = without_final(front, 'ㄴ') {
+            split_predicate(&stem, Some(PredicateKind::Verb), question)?
+        } else {
+            let predicate = split_predicate(front, None, question)?;
+            match predicate.kind {
+                PredicateKind::Verb if !predicate.past && !predicate.future => Predicate {
+                    kind: PredicateKind::Adjective,
+                    ..predicate
+                },
+                _ => predicate,
+            }
+        };
+        return Some((predicate, SpeechLevel::Plain));
+    }
+    if question {
+        if let Some(front) = word.strip_suffix('니').or_else(|| word.strip_suffix('냐')) {
+            let stem = front.strip_suffix('느').unwrap_or(front);
+            if !stem.is_empty() {
+                let predicate = split_predicate(stem, None, question)?;
+                let plain_question = Some(word[stem.len()..].to_string());
+                return Some((
+                    Predicate {
+                        plain_question,
+                        ..predicate
+                    },
+                    SpeechLevel::Plain,
+                ));
+            }
+        }
+    }
+
+    // 해체: 먹어, 학생이야
+    let predicate = read_copula(word, &["이야", "야", "야"], question)
+        .or_else(|| read_infinitive(word, question))?;
+    Some((predicate, SpeechLevel::Casual))
+}
+
+/// Reads a copula ending such as 이에(요) or 야 after a noun, or 아니에(요) and 아니야.
+/// The last of `endings` is only read after 아니.
+fn read_copula(front: &str, endings: &[&str], question: bool) -> Option<Predicate> {
+    let ending = endings.iter().find(|ending| front.ends_with(*ending))?;
+    let noun = &front[..front.len() - ending.len()];
+    let (noun, stem) = match noun {
+        "아니" => ("", "아니"),
+        "" => return None,
+        _ if Some(ending) == endings.last() => return None,
+        _ => (noun, "이"),
+    };
+    Some(Predicate {
+        noun: noun.to_string(),
+        stem: stem.to_string(),
+        kind: PredicateKind::Copula,
+        honorific: false,
+        past: false,
+        future: false,
+        question,
+        plain_question: None,
+    })
+}
+
+/// Reads the polite honorific 세요 (가세요, 읽으세요).
+fn read_honorific_polite(front: &str, question: bool) -> Option<Predicate> {
+    let stem = front.strip_suffix('세').filter(|stem| !stem.is_empty())?;
+    let stem = match stem.strip_suffix('으') {
+        Some(consonant_stem) if !consonant_stem.is_empty() => consonant_stem,
+        _ => stem,
+    };
+    let predicate = split_predicate(stem, None, question)?;
+    Some(Predicate {
+        honorific: true,
+        ..predicate
+    })
+}
+
+/// Reads a -아/어 form (먹어, 봐, 했어) back into its predicate.
+fn read_infinitive(front: &str, question: bool) -> Option<Predicate> {
+    if OTHER_ENDINGS.iter().any(|ending| front.ends_with(ending)) {
+        return None;
+    }
+    // the propositive -자 (가자, 먹자)
+    if front.chars().count() > 1 && front.ends_with('자') {
+        return None;
+    }
+    let stem = infinitive_stem(front)?;
+    split_predicate(&stem, None, question)
+}
+
+/// Splits a stem with its pre-final endings (가셨, 학생이었, 알겠, 먹) into a predicate.
+fn split_predicate(full: &str, kind: Option<PredicateKind>, question: bool) -> Option<Predicate> {
+    // 겠 comes last (가셨겠, 알겠) and its ㅆ is not the past marker
+    let (full, future) = match full.strip_suffix('겠').filter(|stem| !stem.is_empty()) {
+        Some(stem) => (stem, true),
+        None => (full, false),
+    };
+    let mut stem = full.to_string();
+    let mut past = false;
+    let mut honorific = false;
+
+    // the copula: 학생이, 학생이었, 의사였, 아니, 아니었
+    let copula = |noun: &str, stem: &str, past: bool| Predicate {
+        noun: noun.to_string(),
+        stem: stem.to_string(),
+        kind: PredicateKind::Copula,
+        honorific: false,
+        past,
+        future,
+        question,
+        plain_question: None,
+    };
+    if kind.is_none() && !I_VERBS.iter().any(|verb| full.ends_with(verb)) {
+        match full {
+            "아니" => return Some(copula("", "아니", false)),
+            "아니었" => return Some(copula("", "아니", true)),
+            _ => {}
+        }
+        if let Some(noun) = full.strip_suffix("이었").filter(|noun| !noun.is_empty()) {
+            return Some(copula(noun, "이", true));
+        }
+        if let Some(noun) = full.strip_suffix('였').filter(|noun| !noun.is_empty()) {
+            return Some(copula(noun, "이", true));
+        }
+        if let Some(noun) = full.strip_suffix('이').filter(|noun| !noun.is_empty()) {
+            return Some(copula(noun, "이", false));
+        }
+    }
+
+    if let Some(open) = without_final(&stem, 'ㅆ').filter(|_| !stem.ends_with('있')) {
+        stem = infinitive_stem(&open)?;
+        past = true;
+    }
+    if let Some(plain) = stem.strip_suffix('시') {
+        if !plain.is_empty() && !NATIVE_SI.iter().any(|native| stem.ends_with(native)) {
+            honorific = true;
+            stem = match plain.strip_suffix('으') {
+                Some(consonant_stem) if !consonant_stem.is_empty() => consonant_stem,
+                _ => plain,
+            }
+            .to_string();
+        }
+    }
+    if stem.is_empty() {
+        return None;
+    }
+
+    let kind = kind.unwrap_or(if is_adjective(&stem) {
+        PredicateKind::Adjective
+    } else {
+        PredicateKind::Verb
+    });
+    Some(Predicate {
+        noun: String::new(),
+        stem,
+        kind,
+        honorific,
+        past,
+        future,
+        question,
+        plain_question: None,
+    })
+}
+
+/// Guesses the stem of a -아/어 form, checking each guess by conjugating it again.
+fn infinitive_stem(infinitive: &str) -> Option<String> {
+    let chars: Vec<char> = infinitive.chars().collect();
+    let (&last, prefix) = chars.split_last()?;
+    let (cho, jung, jong) = split_one_letter(last)?;
+    if jong.is_some() {
+        return None;
+    }
+
+    let prefix: String = prefix.iter().collect();
+    let previous = prefix.chars().last().and_then(split_one_letter);
+    let before_previous: String = prefix
+        .chars()
+        .take(prefix.chars().count().saturating_sub(1))
+        .collect();
+    let with_previous_final = |jong: Option<char>| {
+        previous.map(|(p_cho, p_jung, _)| {
+            format!("{}{}", before_previous, syllable(p_cho, p_jung, jong))
+        })
+    };
+    let with_vowel = |vowel: char| format!("{}{}", prefix, syllable(cho, vowel, None));
+
+    let mut candidates = Vec::new();
+    match (cho, jung) {
+        _ if last == '해' => candidates.push(format!("{}하", prefix)),
+        _ if last == '여' && prefix.ends_with('하') => candidates.push(prefix.clone()),
+        // 불러 → 부르, 몰라 → 모르
+        ('ㄹ', 'ㅏ' | 'ㅓ') if previous.is_some_and(|(_, _, jong)| jong == Some('ㄹ')) => {
+            candidates.extend(with_previous_final(None).map(|stem| stem + "르"));
+            candidates.push(infinitive.to_string());
+        }
+        // 먹어 → 먹, 나아 → 낫, 들어 → 들 or 듣
+        ('ㅇ', 'ㅏ' | 'ㅓ') if !prefix.is_empty() => {
+            candidates.push(prefix.clone());
+            if previous.is_some_and(|(_, _, jong)| jong.is_none()) {
+                candidates.extend(with_previous_final(Some('ㅅ')));
+            }
+            if previous.is_some_and(|(_, _, jong)| jong == Some('ㄹ')) {
+                candidates.extend(with_previous_final(Some('ㄷ')));
+            }
+        }
+        // 도와 → 돕, 추워 → 춥, 배워 → 배우, 나와 → 나오
+        ('ㅇ', 'ㅘ' | 'ㅝ') if previous.is_some_and(|(_, _, jong)| jong.is_none()) => {
+            let vowel_stem = format!("{}{}", prefix, if jung == 'ㅘ' { '오' } else { '우' });
+            let bieup_stem = with_previous_final(Some('ㅂ'));
+            let bieup_first = if jung == 'ㅘ' {
+                matches!(prefix.as_str(), "도" | "고")
+            } else {
+                !WU_STEMS.iter().any(|stem| vowel_stem.ends_with(stem))
+            };
+            if bieup_first {
+                candidates.extend(bieup_stem);
+                candidates.push(vowel_stem);
+            } else {
+                candidates.push(vowel_stem);
+                candidates.extend(bieup_stem);
+            }
+        }
+        (_, 'ㅘ') => candidates.push(with_vowel('ㅗ')),
+        (_, 'ㅝ') => candidates.push(with_vowel('ㅜ')),
+        (_, 'ㅙ') => candidates.push(with_vowel('ㅚ')),
+        // 마셔 → 마시, but 켜 → 켜
+        (_, 'ㅕ') if !prefix.is_empty() => {
+            candidates.push(with_vowel('ㅣ'));
+            candidates.push(infinitive.to_string());
+        }
+        // 파래 → 파랗, 하얘 → 하얗
+        (_, 'ㅐ' | 'ㅒ') => {
+            let hieut_stems = ['ㅏ', 'ㅓ', 'ㅑ', 'ㅕ']
+                .into_iter()
+                .map(|vowel| syllable(cho, vowel, Some('ㅎ')))
+                .filter(|hieut| HIEUT_SYLLABLES.contains(hieut))
+                .map(|hieut| format!("{}{}", prefix, hieut));
+            candidates.extend(hieut_stems);
+            candidates.push(infinitive.to_string());
+        }
+        // 바빠 → 바쁘, 써 → 쓰, 퍼 → 푸, but 가 → 가, 싸 → 싸
+        (_, 'ㅏ' | 'ㅓ') => {
+            let eu_first = matches!(cho, 'ㅃ' | 'ㅍ')
+                || (jung == 'ㅓ' && matches!(cho, 'ㄲ' | 'ㄸ' | 'ㅆ' | 'ㅋ' | 'ㅌ'));
+            if last == '퍼' {
+                candidates.push(with_vowel('ㅜ'));
+            }
+            if eu_first {
+                candidates.push(with_vowel('ㅡ'));
+                candidates.push(infinitive.to_string());
+            } else {
+                candidates.push(infinitive.to_string());
+                candidates.push(with_vowel('ㅡ'));
+            }
+        }
+        _ => candidates.push(infinitive.to_string()),
+    }
+
+    candidates
+        .into_iter()
+        .find(|stem| conjugate(stem, "아/어") == infinitive || uncontracted(stem) == infinitive)
+}
+
+/// Writes the -아/어 form of a stem without contraction (되어, 보아, 하여).
+fn uncontracted(stem: &str) -> String {
+    if stem.ends_with('하') {
+        return format!("{}여", stem);
+    }
+    match stem.chars().last().and_then(split_one_letter) {
+        Some((_, 'ㅏ' | 'ㅗ', None)) => format!("{}아", stem),
+        Some((_, _, None)) => format!("{}어", stem),
+        _ => conjugate(stem, "아/어"),
+    }
+}
+
+/// Removes `jong` from the last syllable of `word`, if it ends with it.
+fn without_final(word: &str, jong: char) -> Option<String> {
+    let mut chars: Vec<char> = word.chars().collect();
+    let last = chars.last_mut()?;
+    let (cho, jung, final_jamo) = split_one_letter(*last)?;
+    if final_jamo != Some(jong) {
+        return None;
+    }
+    *last = syllable(cho, jung, None);
+    Some(chars.into_iter().collect())
+}
+
+fn is_adjective(stem: &str) -> bool {
+    ADJECTIVES.contains(&stem)
+        || stem.strip_suffix('하').is_some_and(|root| {
+            HA_ADJECTIVES
+                .iter()
+                .any(|adjective| root.ends_with(adjective))
+        })
+        || stem.ends_with('있')
+        || stem.ends_with('없')
+        || stem.ends_with("스럽")
+        || stem.ends_with("롭")
+        || match conjugation_type(stem) {
+            ConjugationType::Hieut => true,
+            // ㅂ irregular stems are adjectives but for 돕다, 눕다, 줍다 and 굽다
+            ConjugationType::Bieup => !["돕", "눕", "줍", "굽"]
+                .iter()
+                .any(|verb| stem.ends_with(verb)),
+            _ => false,
+        }
+}
+
+/// Writes a predicate out at the given speech level.
+fn render(predicate: &Predicate, level: SpeechLevel) -> String {
+    let noun = predicate.noun.as_str();
+    let noun_has_final = noun
+        .chars()
+        .last()
+        .and_then(split_one_letter)
+        .is_some_and(|(_, _, jong)| jong.is_some());
+
+    // 시, 었 and 겠 go on the stem first (가시, 가셨, 학생이었, 의사였, 알겠)
+    let mut stem = predicate.stem.clone();
+    if predicate.honorific {
+        stem = conjugate(&stem, "(으)시");
+    }
+    if predicate.past {
+        stem = match (predicate.kind, stem.as_str()) {
+            (PredicateKind::Copula, "이") if !noun_has_final => "였".to_string(),
+            (PredicateKind::Copula, _) => format!("{}었", stem),
+            _ => conjugate(&stem, "았/었"),
+        };
+    }
+    if predicate.future {
+        stem.push('겠');
+    }
+
+    let present_copula =
+        predicate.kind == PredicateKind::Copula && !predicate.past && !predicate.future;
+    let ending = match level {
+        SpeechLevel::Formal if predicate.question => conjugate(&stem, "ㅂ/습니까"),
+        SpeechLevel::Formal => conjugate(&stem, "ㅂ/습니다"),
+        SpeechLevel::Polite if present_copula => match stem.as_str() {
+            "이" if !noun_has_final => "예요".to_string(),
+            _ => format!("{}에요", stem),
+        },
+        SpeechLevel::Polite if predicate.honorific && !predicate.past && !predicate.future => {
+            conjugate(&predicate.stem, "(으)세요")
+        }
+        SpeechLevel::Polite => conjugate(&stem, "아/어요"),
+        SpeechLevel::Casual if present_copula => match stem.as_str() {
+            "이" if !noun_has_final => "야".to_string(),
+            _ => format!("{}야", stem),
+        },
+        SpeechLevel::Casual => conjugate(&stem, "아/어"),
+        SpeechLevel::Plain if present_copula && stem == "이" && !noun_has_final => {
+            if predicate.question { "니" } else { "다" }.to_string()
+        }
+        SpeechLevel::Plain if predicate.question => match &predicate.plain_question {
+            Some(ending) => format!("{}{}", stem, ending),
+            None => conjugate(&stem, "니"),
+        },
+        SpeechLevel::Plain
+            if predicate.kind == PredicateKind::Verb && !predicate.past && !predicate.future =>
+        {
+            conjugate(&stem, "ㄴ/는다")
+        }
+        SpeechLevel::Plain => conjugate(&stem, "다"),
+    };
+
+    format!("{}{}", noun, ending)
+}
@@ -1,10 +1,22 @@
+// test_combine_status_check passes owned strings, as written before the lint existed
+#![allow(clippy::unnecessary_to_owned)]
+
 extern crate rustkorean;
-use rustkorean::compose_korean::{combine_status_check, make_one_letter};
+use rustkorean::compose_korean::{combine_status_check, make_one_letter, split_one_letter};
+use rustkorean::conjugation::{conjugate_as, conjugation_type};
 use rustkorean::{
-    check_korean, classify_korean, compose_korean, create_double_consonant,
-    english_input_to_korean, first_letter_check, last_letter_check, middle_letter_check,
-    syllable_check, KoreanType, SyllableType,
+    change_speech_level, check_korean, classify_korean, compose_korean, conjugate, convert_layout,
+    create_double_consonant, decompose_korean, deromanize, detect_speech_level,
+    english_input_to_korean, final_sound, first_letter_check, from_yale, josa, josa_format,
+    keystroke_count, keystrokes_per_minute, keystrokes_to_korean, korean_to_keystrokes,
+    last_letter_check, middle_letter_check, needs_saisiot, pronounce, pronounce_with_trace,
+    representative_final, romanize, romanize_mccune_reischauer, romanize_name, romanize_with,
+    set_honorific, slugify, slugify_with, strip_josa, syllable_check, to_cyrillic, to_ipa, to_yale,
+    ConjugationType, Hyphenation, JosaPair, KeyboardLayout, KeypadEvent, KeypadInput, KeypadLayout,
+    KoreanType, Lexicon, NameSeparator, PronunciationDictionary, RomanizeOptions, SlugOptions,
+    SoundRule, SpeechLevel, SyllableType, WordOrigin,
 };
+use std::time::Duration;
 
 #[test]
 fn test_check_korean() {
@@ -70,6 +82,17 @@ fn test_compose_korean() {
     assert_eq!(result, "한글 ABC123.");
 }
 
+#[test]
+fn test_compose_korean_compound_jamo() {
+    assert_eq!(compose_korean(vec!['ㄷ', 'ㅏ', 'ㄹ', 'ㄱ']), "닭");
+    assert_eq!(compose_korean(vec!['ㄱ', 'ㅗ', 'ㅏ']), "과");
+    assert_eq!(compose_korean(vec!['ㄱ', 'ㅏ', 'ㄸ', 'ㅏ']), "가따");
+    assert_eq!(
+        compose_korean(vec!['ㅇ', 'ㅏ', 'ㄴ', 'ㅈ', 'ㅇ', 'ㅏ']),
+        "앉아"
+    );
+}
+
 #[test]
 fn test_combine_status_check() {
     assert!(combine_status_check(&"ㄱ".to_string(), &'ㅏ'));
@@ -99,3 +122,626 @@ fn test_english_input_to_korean() {
     let english_key: char = 'r';
     assert_eq!(english_input_to_korean(english_key), 'ㄱ');
 }
+
+#[test]
+fn test_split_one_letter() {
+    assert_eq!(split_one_letter('닭'), Some(('ㄷ', 'ㅏ', Some('ㄺ'))));
+    assert_eq!(split_one_letter('A'), None);
+    assert_eq!(decompose_korean("과자"), vec!['ㄱ', 'ㅘ', 'ㅈ', 'ㅏ']);
+}
+
+#[test]
+fn test_keystrokes() {
+    let dubeolsik = KeyboardLayout::Dubeolsik;
+    let sebeolsik = KeyboardLayout::Sebeolsik390;
+
+    assert_eq!(korean_to_keystrokes("닭과 빵", dubeolsik), "ekfrrhk Qkd");
+    assert_eq!(keystrokes_to_korean("ekfrrhk Qkd", dubeolsik), "닭과 빵");
+    assert_eq!(keystrokes_to_korean("ekfrdl", dubeolsik), "닭이");
+    assert_eq!(keystrokes_to_korean("Tkdrp ro", dubeolsik), "쌍게 개");
+    assert_eq!(korean_to_keystrokes("닭과 빵", sebeolsik), "uf@k/f ;;fa");
+    assert_eq!(keystrokes_to_korean("uf@k/f ;;fa", sebeolsik), "닭과 빵");
+}
+
+#[test]
+fn test_convert_layout() {
+    let typed = keystrokes_to_korean("jfsheamfncj4", KeyboardLayout::Dubeolsik);
+    let fixed = convert_layout(
+        &typed,
+        KeyboardLayout::Dubeolsik,
+        KeyboardLayout::Sebeolsik390,
+    );
+    assert_eq!(fixed, "안녕하세요");
+    assert_eq!(
+        convert_layout(
+            &format!("Hello {} OK", typed),
+            KeyboardLayout::Dubeolsik,
+            KeyboardLayout::Sebeolsik390,
+        ),
+        "Hello 안녕하세요 OK"
+    );
+}
+
+fn type_on_keypad(input: &mut KeypadInput, buttons: &str) {
+    for button in buttons.chars() {
+        match button {
+            '>' => input.push(KeypadEvent::Next),
+            '<' => input.push(KeypadEvent::Backspace),
+            _ => input.push(KeypadEvent::Button(button)),
+        }
+    }
+}
+
+#[test]
+fn test_cheonjiin_input() {
+    let mut input = KeypadInput::new(KeypadLayout::Cheonjiin);
+    // 까 (4 4 4, ㅏ), 치 (9 9, ㅣ)
+    type_on_keypad(&mut input, "44412991");
+    assert_eq!(input.text(), "까치");
+
+    input.clear();
+    // without "next" the second press of 4 turns ㄱ into ㅋ
+    type_on_keypad(&mut input, "012441");
+    assert_eq!(input.text(), "아키");
+
+    input.clear();
+    type_on_keypad(&mut input, "0124>41");
+    assert_eq!(input.text(), "악기");
+
+    // backspace removes the vowel stroke and then the whole ㄱ
+    type_on_keypad(&mut input, "<<");
+    assert_eq!(input.text(), "악");
+    type_on_keypad(&mut input, "<<");
+    assert_eq!(input.text(), "이");
+    type_on_keypad(&mut input, "2");
+    assert_eq!(input.text(), "아");
+}
+
+#[test]
+fn test_naratgeul_input() {
+    let mut input = KeypadInput::new(KeypadLayout::Naratgeul);
+    // 닭 (ㄴ + stroke = ㄷ), 빵 (ㅁ + stroke = ㅂ, double = ㅃ), 개 (ㅏ + ㅣ = ㅐ)
+    type_on_keypad(&mut input, "2*341>5*#38139");
+    assert_eq!(input.text(), "닭빵개");
+
+    // ㅏ and ㅓ share a button
+    input.clear();
+    type_on_keypad(&mut input, "8336");
+    assert_eq!(input.text(), "어ㅗ");
+    type_on_keypad(&mut input, "<");
+    assert_eq!(input.text(), "어");
+}
+
+#[test]
+fn test_sky_input() {
+    let mut input = KeypadInput::new(KeypadLayout::Sky);
+    // 사과 (ㅅ = 7 7), 참치 (ㅊ = * *)
+    type_on_keypad(&mut input, "773193 **37**2");
+    assert_eq!(input.text(), "사과 참치");
+}
+
+#[test]
+fn test_keystroke_count() {
+    // 안(3) 녕(3) 하(2) 세(2) 요(2) + space
+    assert_eq!(
+        keystroke_count("안녕 하세요", KeyboardLayout::Dubeolsik),
+        13
+    );
+    // ㅘ and ㄺ take two keys, ㅖ needs Shift
+    assert_eq!(keystroke_count("과", KeyboardLayout::Dubeolsik), 3);
+    assert_eq!(keystroke_count("맑", KeyboardLayout::Dubeolsik), 4);
+    assert_eq!(keystroke_count("계", KeyboardLayout::Dubeolsik), 3);
+    // a single shifted key types ㄺ on Sebeolsik 390
+    assert_eq!(keystroke_count("맑", KeyboardLayout::Sebeolsik390), 4);
+
+    let speed = keystrokes_per_minute("과", KeyboardLayout::Dubeolsik, Duration::from_secs(1));
+    assert_eq!(speed, 180.0);
+    assert_eq!(
+        keystrokes_per_minute("과", KeyboardLayout::Dubeolsik, Duration::ZERO),
+        0.0
+    );
+}
+
+#[test]
+fn test_romanize() {
+    assert_eq!(romanize("한국"), "hanguk");
+    assert_eq!(romanize("서울"), "seoul");
+    assert_eq!(romanize("신라"), "silla");
+    assert_eq!(romanize("종로"), "jongno");
+    assert_eq!(romanize("같이"), "gachi");
+    assert_eq!(romanize("좋고"), "joko");
+    assert_eq!(romanize("왕십리"), "wangsimni");
+    assert_eq!(romanize("압구정"), "apgujeong");
+    assert_eq!(romanize("대관령"), "daegwallyeong");
+    assert_eq!(romanize("Seoul 서울+역!"), "Seoul seoullyeok!");
+    assert_eq!(romanize("솜+이불"), "somnibul");
+    assert_eq!(
+        PronunciationDictionary::standard().romanize("서울역"),
+        "seoullyeok"
+    );
+}
+
+#[test]
+fn test_romanize_with() {
+    let per_syllable = RomanizeOptions {
+        pronunciation: false,
+        hyphenation: Hyphenation::None,
+    };
+    assert_eq!(romanize_with("같이", per_syllable), "gati");
+    assert_eq!(romanize_with("종로", per_syllable), "jongro");
+
+    let ambiguous = RomanizeOptions {
+        hyphenation: Hyphenation::Ambiguous,
+        ..Default::default()
+    };
+    assert_eq!(romanize_with("반구대", ambiguous), "ban-gudae");
+    assert_eq!(romanize_with("부산", ambiguous), "busan");
+
+    let syllables = RomanizeOptions {
+        hyphenation: Hyphenation::Syllables,
+        ..Default::default()
+    };
+    assert_eq!(romanize_with("신라", syllables), "sil-la");
+}
+
+#[test]
+fn test_romanize_mccune_reischauer() {
+    assert_eq!(romanize_mccune_reischauer("서울"), "sŏul");
+    assert_eq!(romanize_mccune_reischauer("대구"), "taegu");
+    assert_eq!(romanize_mccune_reischauer("인천"), "inch'ŏn");
+    assert_eq!(romanize_mccune_reischauer("신라"), "silla");
+    assert_eq!(romanize_mccune_reischauer("학교"), "hakkyo");
+    assert_eq!(romanize_mccune_reischauer("떡볶이"), "ttŏkpokki");
+    assert_eq!(romanize_mccune_reischauer("물고기"), "mulgogi");
+    assert_eq!(romanize_mccune_reischauer("쉬다"), "shwida");
+}
+
+#[test]
+fn test_yale_round_trip() {
+    assert_eq!(to_yale("아빠 값이"), "appa kaps.i");
+    assert_eq!(from_yale("appa kaps.i"), Some("아빠 값이".to_string()));
+
+    for code in 0xAC00..=0xD7A3 {
+        let syllable = char::from_u32(code).unwrap().to_string();
+        for word in [
+            syllable.clone(),
+            format!("{}아", syllable),
+            format!("{}까", syllable),
+            format!("애{}", syllable),
+        ] {
+            assert_eq!(from_yale(&to_yale(&word)), Some(word));
+        }
+    }
+}
+
+#[test]
+fn test_deromanize() {
+    assert_eq!(deromanize("annyeong haseyo")[0], "안녕 하세요");
+    assert_eq!(deromanize("Seoul")[0], "서울");
+    assert_eq!(deromanize("silla"), vec!["실라"]);
+    assert_eq!(deromanize("hangang"), vec!["한강", "항앙"]);
+    assert!(deromanize("xyz").is_empty());
+    assert!(deromanize("").is_empty());
+    assert!(deromanize("123 !").is_empty());
+}
+
+#[test]
+fn test_romanize_name() {
+    assert_eq!(romanize_name("박지혜", NameSeparator::None), "Park Jihye");
+    assert_eq!(
+        romanize_name("최석민", NameSeparator::Hyphen),
+        "Choi Seok-min"
+    );
+    assert_eq!(
+        romanize_name("황보 영", NameSeparator::Space),
+        "Hwangbo Yeong"
+    );
+    assert_eq!(romanize_name("맹꽁", NameSeparator::None), "Maeng Kkong");
+    assert_eq!(romanize_name("Kim Jihye", NameSeparator::None), "Kim Jihye");
+}
+
+#[test]
+fn test_slugify() {
+    assert_eq!(
+        slugify("한국어 -- 자연어 처리!!"),
+        "hangugeo-jayeoneo-cheori"
+    );
+    assert_eq!(slugify("HELLO 세계"), "hello-segye");
+
+    // 대관령 is daegwallyeong, so the limit stops before "lyeong" instead of cutting it
+    let short = SlugOptions {
+        max_length: Some(12),
+        ..Default::default()
+    };
+    assert_eq!(slugify_with("대관령 여행", short), "daegwal");
+
+    let hangul = SlugOptions {
+        max_length: Some(5),
+        keep_hangul: true,
+    };
+    assert_eq!(slugify_with("《서울》 여행기", hangul), "서울-여행");
+}
+
+#[test]
+fn test_to_cyrillic() {
+    assert_eq!(to_cyrillic("대구"), "Тэгу");
+    assert_eq!(to_cyrillic("제주"), "Чеджу");
+    assert_eq!(to_cyrillic("울산"), "Ульсан");
+    assert_eq!(to_cyrillic("신라"), "Силла");
+    assert_eq!(to_cyrillic("중앙 강원도"), "Чунъан Канвондо");
+    assert_eq!(to_cyrillic("서울, 평양"), "Сеул, Пхеньян");
+}
+
+#[test]
+fn test_to_ipa() {
+    // 학교 is pronounced 학꾜
+    assert_eq!(to_ipa("학교"), "hak̚k͈jo");
+    assert_eq!(to_ipa("감기"), "kamɡi");
+    assert_eq!(to_ipa("신라"), "ɕilla");
+    assert_eq!(to_ipa("먹는 사람"), "mʌŋnɯn saɾam");
+    assert_eq!(to_ipa("좋다"), "t͡ɕotʰa");
+}
+
+#[test]
+fn test_pronounce() {
+    assert_eq!(pronounce("국밥"), "국빱");
+    assert_eq!(pronounce("값이"), "갑씨");
+    assert_eq!(pronounce("밝히다"), "발키다");
+    assert_eq!(pronounce("앉다"), "안따");
+    assert_eq!(pronounce("싫어"), "시러");
+    assert_eq!(pronounce("넋 없다"), "너 겁따");
+    // linking across a space only happens before ㅏ ㅓ ㅗ ㅜ ㅟ
+    assert_eq!(pronounce("밥 이야기"), "밥 이야기");
+    assert_eq!(pronounce("Hello, 한국어!"), "Hello, 한구거!");
+    // ㄴ+ㄹ of a Sino-Korean suffix is read ㄴ+ㄴ (제20항 다만)
+    assert_eq!(pronounce("의견란"), "의견난");
+    assert_eq!(pronounce("생산량"), "생산냥");
+    assert_eq!(pronounce("판단력"), "판단녁");
+    assert_eq!(pronounce("권력"), "궐력");
+}
+
+#[test]
+fn test_pronounce_with_trace() {
+    let (pronunciation, changes) = pronounce_with_trace("독립 만세");
+    assert_eq!(pronunciation, "동닙 만세");
+    let rules: Vec<SoundRule> = changes.iter().map(|change| change.rule).collect();
+    assert_eq!(
+        rules,
+        vec![SoundRule::RieulNasalization, SoundRule::Nasalization]
+    );
+    assert_eq!(changes[1].position, 0);
+    assert_eq!(changes[1].before, (Some('ㄱ'), Some('ㄴ')));
+    assert_eq!(changes[1].after, (Some('ㅇ'), Some('ㄴ')));
+
+    let (_, changes) = pronounce_with_trace("옷 안");
+    assert_eq!(changes[0].to_string(), "제9항 받침 대표음: ㅅ → ㄷ");
+    assert_eq!(changes[1].to_string(), "제15항 연음: ㄷ+ㅇ → ㄷ");
+
+    let (_, changes) = pronounce_with_trace("값이");
+    assert_eq!(changes[0].to_string(), "제14항 연음: ㅄ+ㅇ → ㅂ+ㅆ");
+}
+
+#[test]
+fn test_final_sound() {
+    let finals = create_double_consonant(vec!['ㄹ', 'ㄱ', 'ㄹ', 'ㅂ', 'ㅂ', 'ㅅ']);
+    let sounds: Vec<char> = finals.into_iter().map(representative_final).collect();
+    assert_eq!(sounds, vec!['ㄱ', 'ㄹ', 'ㅂ']);
+    assert_eq!(representative_final('ㅆ'), 'ㄷ');
+
+    assert_eq!(final_sound('밟', Some('고')), Some('ㅂ'));
+    assert_eq!(final_sound('여', Some('덟')), None);
+    assert_eq!(final_sound('닭', Some('과')), Some('ㄱ'));
+    assert_eq!(pronounce("밟다"), "밥따");
+    assert_eq!(pronounce("넓죽하다"), "넙쭈카다");
+    assert_eq!(pronounce("맑게"), "말께");
+    assert_eq!(pronounce("닭고기"), "닥꼬기");
+}
+
+#[test]
+fn test_pronunciation_dictionary() {
+    let dictionary = PronunciationDictionary::standard();
+    assert_eq!(dictionary.pronounce("김밥 먹고"), "김밥 먹꼬");
+    assert_eq!(
+        dictionary.pronounce_variants("멋있다 김밥"),
+        vec!["머딛따 김밥", "머딛따 김빱", "머싣따 김밥", "머싣따 김빱"]
+    );
+    assert_eq!(dictionary.romanize("효과 불법"), "hyogwa bulbeop");
+    assert_eq!(dictionary.pronounce("결단력 공권력"), "결딴녁 공꿘녁");
+    assert_eq!(dictionary.pronounce("갈등 발전 일시"), "갈뜽 발쩐 일씨");
+    assert_eq!(romanize_mccune_reischauer("인기"), "in'gi");
+    assert_eq!(dictionary.romanize_mccune_reischauer("인기"), "inkki");
+    assert_eq!(to_ipa("갈등"), "kaldɯŋ");
+    assert_eq!(dictionary.to_ipa("갈등"), "kalt͈ɯŋ");
+    assert_eq!(to_cyrillic("멋있다"), "Моситта");
+    assert_eq!(dictionary.to_cyrillic("멋있다"), "Модитта");
+    assert_eq!(slugify("맛있다 김밥"), "masitda-gimbap");
+    assert_eq!(
+        dictionary.slugify_with("맛있다 김밥", SlugOptions::default()),
+        "maditda-gimbap"
+    );
+
+    let path = std::env::temp_dir().join("rustkorean_pronunciation_dictionary.txt");
+    std::fs::write(&path, "# loanwords\n버스 뻐스\n\n잼 쨈 잼\n").unwrap();
+    let mut dictionary = PronunciationDictionary::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(dictionary.pronounce("버스 잼"), "뻐스 쨈");
+    assert!(dictionary.insert("잼", "잼"));
+    assert!(!dictionary.insert("jam", "잼"));
+    assert_eq!(
+        dictionary.get("잼"),
+        Some(&["쨈".to_string(), "잼".to_string()][..])
+    );
+
+    assert_eq!(
+        PronunciationDictionary::parse("버스\n"),
+        Err("line 1: invalid entry \"버스\"".to_string())
+    );
+}
+
+#[test]
+fn test_compound_pronunciation() {
+    assert_eq!(pronounce("솜+이불"), "솜니불");
+    assert_eq!(pronounce("깻+잎"), "깬닙");
+    assert_eq!(pronounce("들+일"), "들릴");
+    assert_eq!(pronounce("색+연필"), "생년필");
+    assert_eq!(pronounce("겉+옷"), "거돋");
+    assert_eq!(pronounce("1+1"), "1+1");
+
+    let (_, changes) = pronounce_with_trace("한+여름");
+    assert_eq!(changes[0].rule, SoundRule::NieunInsertion);
+    assert_eq!(changes[0].to_string(), "제29항 ㄴ 첨가: ㄴ+ㅇ → ㄴ+ㄴ");
+
+    let native = WordOrigin::Native;
+    assert!(needs_saisiot("이+몸", "인몸", native, native));
+    assert!(needs_saisiot(
+        "귀+병",
+        "귀뼝",
+        native,
+        WordOrigin::SinoKorean
+    ));
+    assert!(!needs_saisiot(
+        "피자+집",
+        "피자찝",
+        WordOrigin::Loanword,
+        native
+    ));
+    assert!(!needs_saisiot("솜+이불", "솜니불", native, native));
+}
+
+#[test]
+fn test_josa() {
+    assert_eq!(josa("고양이", JosaPair::EunNeun), "고양이는");
+    assert_eq!(josa("강", JosaPair::EunNeun), "강은");
+    assert_eq!(josa("나무", JosaPair::IGa), "나무가");
+    assert_eq!(josa("산", JosaPair::GwaWa), "산과");
+    assert_eq!(josa("바다", JosaPair::GwaWa), "바다와");
+    assert_eq!(josa("연필", JosaPair::EuroRo), "연필로");
+    assert_eq!(josa("학교", JosaPair::EuroRo), "학교로");
+    assert_eq!(josa("영숙", JosaPair::AYa), "영숙아");
+    assert_eq!(josa("조국", JosaPair::IyeoYeo), "조국이여");
+    assert_eq!(josa("친구", JosaPair::Copula), "친구다");
+    assert_eq!(josa("π", JosaPair::EunNeun), "π은(는)");
+}
+
+#[test]
+fn test_josa_reading() {
+    assert_eq!(josa("10", JosaPair::IGa), "10이");
+    assert_eq!(josa("1,000", JosaPair::EuroRo), "1,000으로");
+    assert_eq!(josa("2", JosaPair::EunNeun), "2는");
+    assert_eq!(josa("7", JosaPair::EuroRo), "7로");
+    assert_eq!(josa("100000000", JosaPair::IGa), "100000000이");
+    assert_eq!(josa("10000000000000", JosaPair::IGa), "10000000000000가");
+    assert_eq!(josa("100000000000000", JosaPair::IGa), "100000000000000가");
+    assert_eq!(josa("300000", JosaPair::EulReul), "300000을");
+    assert_eq!(josa("HTML", JosaPair::EulReul), "HTML을");
+    assert_eq!(josa("API", JosaPair::EulReul), "API를");
+    assert_eq!(josa("Rust", JosaPair::EunNeun), "Rust는");
+    assert_eq!(josa("Google", JosaPair::EuroRo), "Google로");
+    assert_eq!(josa("iPhone", JosaPair::IGa), "iPhone이");
+    assert_eq!(josa("Facebook", JosaPair::GwaWa), "Facebook과");
+    assert_eq!(josa("\"Apple\"", JosaPair::EunNeun), "\"Apple\"은");
+}
+
+#[test]
+fn test_josa_format() {
+    let values = [("user", "영희"), ("count", "3"), ("app", "Slack")];
+    assert_eq!(
+        josa_format(
+            "{user}은(는) {count}개의 메시지를 {app}에서 받았어요",
+            &values
+        ),
+        "영희는 3개의 메시지를 Slack에서 받았어요"
+    );
+    assert_eq!(
+        josa_format("{app}과/와 {user}아/야, 안녕", &values),
+        "Slack과 영희야, 안녕"
+    );
+    assert_eq!(
+        josa_format("{{user}} {missing}이/가 {count}(이)다", &values),
+        "{user} {missing}이/가 3이다"
+    );
+}
+
+#[test]
+fn test_conjugate() {
+    assert_eq!(conjugate("먹다", "아/어요"), "먹어요");
+    assert_eq!(conjugate("보다", "아/어요"), "봐요");
+    assert_eq!(conjugate("되다", "았/었다"), "됐다");
+    assert_eq!(conjugate("하다", "아/어요"), "해요");
+    assert_eq!(conjugate("바쁘다", "아/어요"), "바빠요");
+    assert_eq!(conjugate("듣다", "아/어요"), "들어요");
+    assert_eq!(conjugate("받다", "아/어요"), "받아요");
+    assert_eq!(conjugate("돕다", "아/어요"), "도와요");
+    assert_eq!(conjugate("춥다", "(으)면"), "추우면");
+    assert_eq!(conjugate("낫다", "아/어요"), "나아요");
+    assert_eq!(conjugate("파랗다", "아/어요"), "파래요");
+    assert_eq!(conjugate("파랗다", "(으)면"), "파라면");
+    assert_eq!(conjugate("부옇다", "아/어요"), "부예요");
+    assert_eq!(conjugate("부르다", "았/었다"), "불렀다");
+    assert_eq!(conjugate("따르다", "아/어요"), "따라요");
+    assert_eq!(conjugate("푸르다", "아/어요"), "푸르러요");
+    assert_eq!(conjugate("푸다", "아/어요"), "퍼요");
+    assert_eq!(conjugate("살다", "(으)니까"), "사니까");
+    assert_eq!(conjugate("살다", "ㅂ/습니다"), "삽니다");
+    assert_eq!(conjugate("먹다", "ㅂ/습니다"), "먹습니다");
+    assert_eq!(conjugate("가다", "(으)ㄹ 거예요"), "갈 거예요");
+    assert_eq!(conjugate("먹다", "고"), "먹고");
+    assert_eq!(conjugate("갔다", "아/어요"), "갔어요");
+    assert_eq!(conjugate("잡았다", "아/어"), "잡았어");
+    assert_eq!(conjugation_type("묻다"), ConjugationType::Digeut);
+    assert_eq!(
+        conjugate_as("묻다", "아/어", ConjugationType::Regular),
+        "묻어"
+    );
+}
+
+#[test]
+fn test_change_speech_level() {
+    assert_eq!(
+        change_speech_level("밥을 먹어요.", SpeechLevel::Formal),
+        "밥을 먹습니다."
+    );
+    assert_eq!(
+        change_speech_level("학교에 갑니까?", SpeechLevel::Polite),
+        "학교에 가요?"
+    );
+    assert_eq!(
+        change_speech_level("저는 학생입니다. 그건 아니에요.", SpeechLevel::Casual),
+        "저는 학생이야. 그건 아니야."
+    );
+    assert_eq!(
+        change_speech_level("날씨가 추워요. 노래를 불러요.", SpeechLevel::Plain),
+        "날씨가 춥다. 노래를 부른다."
+    );
+    assert_eq!(
+        change_speech_level("의사였다.", SpeechLevel::Polite),
+        "의사였어요."
+    );
+    assert_eq!(
+        change_speech_level("커피를 마셨어요!", SpeechLevel::Formal),
+        "커피를 마셨습니다!"
+    );
+    assert_eq!(change_speech_level("안녕", SpeechLevel::Formal), "안녕");
+    assert_eq!(set_honorific("어디 가요?", true), "어디 가세요?");
+    assert_eq!(
+        set_honorific("선생님이 오셨습니다.", false),
+        "선생님이 왔습니다."
+    );
+    assert_eq!(
+        set_honorific("알겠습니다. 감사합니다!", true),
+        "아시겠습니다. 감사하십니다!"
+    );
+    assert_eq!(set_honorific("너 밥 먹었냐?", true), "너 밥 먹으셨냐?");
+    assert_eq!(
+        change_speech_level("내일 가겠습니다.", SpeechLevel::Plain),
+        "내일 가겠다."
+    );
+}
+
+#[test]
+fn test_detect_speech_level() {
+    assert_eq!(
+        detect_speech_level("감사합니다."),
+        Some((SpeechLevel::Formal, 1.0))
+    );
+    assert_eq!(
+        detect_speech_level("어디 가십니까?").map(|(level, _)| level),
+        Some(SpeechLevel::Formal)
+    );
+    assert_eq!(
+        detect_speech_level("저도요.").map(|(level, _)| level),
+        Some(SpeechLevel::Polite)
+    );
+    assert_eq!(
+        detect_speech_level("응 알겠어ㅋㅋ").map(|(level, _)| level),
+        Some(SpeechLevel::Casual)
+    );
+    assert_eq!(
+        detect_speech_level("밥 먹었어 너는?").map(|(level, _)| level),
+        Some(SpeechLevel::Casual)
+    );
+    for casual in ["고마워", "어디 가?", "이거 봐", "줘"] {
+        assert_eq!(
+            detect_speech_level(casual).map(|(level, _)| level),
+            Some(SpeechLevel::Casual)
+        );
+    }
+    assert_eq!(
+        detect_speech_level("뭐 하냐?").map(|(level, _)| level),
+        Some(SpeechLevel::Plain)
+    );
+    let (level, confidence) = detect_speech_level("그래. 알겠습니다.").unwrap();
+    assert_eq!(level, SpeechLevel::Formal);
+    assert!(confidence < 1.0);
+    assert_eq!(detect_speech_level("hello"), None);
+}
+
+#[test]
+fn test_strip_josa() {
+    assert_eq!(strip_josa("학교에서는"), ("학교", vec!["에서", "는"]));
+    assert_eq!(strip_josa("사과를"), ("사과", vec!["를"]));
+    assert_eq!(strip_josa("노을"), ("노을", vec![]));
+    assert_eq!(strip_josa("노를"), ("노를", vec![]));
+    assert_eq!(strip_josa("고양이"), ("고양이", vec![]));
+    assert_eq!(strip_josa("어린이도"), ("어린이", vec!["도"]));
+    assert_eq!(strip_josa("학생이"), ("학생", vec!["이"]));
+    assert_eq!(strip_josa("책을"), ("책", vec!["을"]));
+    assert_eq!(strip_josa("집이"), ("집", vec!["이"]));
+    assert_eq!(strip_josa("차가"), ("차", vec!["가"]));
+    assert_eq!(strip_josa("휴가"), ("휴가", vec![]));
+    assert_eq!(strip_josa("서울로"), ("서울", vec!["로"]));
+    assert_eq!(strip_josa("집으로"), ("집", vec!["으로"]));
+    assert_eq!(strip_josa("친구와도"), ("친구", vec!["와", "도"]));
+    assert_eq!(strip_josa("사이랑"), ("사이", vec!["랑"]));
+    assert_eq!(strip_josa("고양이가"), ("고양이", vec!["가"]));
+    assert_eq!(strip_josa("API를"), ("API", vec!["를"]));
+    assert_eq!(strip_josa("교과서"), ("교과서", vec![]));
+    assert_eq!(strip_josa("hello"), ("hello", vec![]));
+}
+
+#[test]
+fn test_lexicon_analyze() {
+    let mut lexicon = Lexicon::parse(
+        "나,1,1,100,NP,*,F,나,*,*,*,*\n\
+         는,2,2,50,JX,*,T,는,*,*,*,*\n\
+         밥,1,1,100,NNG,*,T,밥,*,*,*,*\n\
+         을,2,2,50,JKO,*,T,을,*,*,*,*\n\
+         먹,3,3,100,VV,*,T,먹,*,*,*,*\n\
+         었,4,4,50,EP,*,T,었,*,*,*,*\n\
+         다,5,5,50,EF,*,F,다,*,*,*,*\n\
+         ㄴ다,5,5,50,EF,*,F,ㄴ다,*,*,*,*\n\
+         가,3,3,100,VV,*,F,가,*,*,*,*\n\
+         가,2,2,100,JKS,*,F,가,*,*,*,*\n\
+         했,3,4,100,VV+EP,*,T,했,Inflect,VV,EP,하/VV/*+였/EP/*\n\
+         어,5,5,50,EF,*,F,어,*,*,*,*\n\
+         \",\",6,6,10,SC,*,F,\",\",*,*,*,*\n",
+    )
+    .unwrap();
+    lexicon
+        .set_connections("7 7\n3 4 0\n4 5 0\n2 5 3000\n")
+        .unwrap();
+
+    let tagged = |text: &str| -> Vec<String> {
+        lexicon
+            .analyze(text)
+            .iter()
+            .map(|morpheme| morpheme.to_string())
+            .collect()
+    };
+    assert_eq!(
+        tagged("나는 밥을 먹었다"),
+        vec!["나/NP", "는/JX", "밥/NNG", "을/JKO", "먹/VV", "었/EP", "다/EF"]
+    );
+    assert_eq!(tagged("간다"), vec!["가/VV", "ㄴ다/EF"]);
+    assert_eq!(tagged("밥,"), vec!["밥/NNG", ",/SC"]);
+    assert_eq!(tagged("했어"), vec!["하/VV", "였/EP", "어/EF"]);
+    assert_eq!(tagged("GPU 3개"), vec!["GPU/SL", "3/SN", "개/UNKNOWN"]);
+    assert_eq!(tagged("갑자기"), vec!["갑자기/UNKNOWN"]);
+    assert_eq!(tagged("갑자기는"), vec!["갑자기/UNKNOWN", "는/JX"]);
+
+    assert_eq!(
+        Lexicon::parse("밥,1,1,100,NNG\n먹,x,3,100,VV\n"),
+        Err("line 2: invalid entry \"먹,x,3,100,VV\"".to_string())
+    );
+    assert!(lexicon.set_connections("2 2\n5 0 10\n").is_err());
+}